@@ -0,0 +1,88 @@
+//? Minimal standard-alphabet base64 with mandatory padding (RFC 4648 section 4)
+
+use crate::error::Error;
+
+const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+pub(crate) fn encode(data: &[u8]) -> String {
+    let mut out = String::with_capacity(data.len().div_ceil(3) * 4);
+    for chunk in data.chunks(3) {
+        let b = [
+            chunk[0],
+            *chunk.get(1).unwrap_or(&0),
+            *chunk.get(2).unwrap_or(&0),
+        ];
+        let n = (b[0] as u32) << 16 | (b[1] as u32) << 8 | b[2] as u32;
+        for i in 0..4 {
+            if i <= chunk.len() {
+                out.push(ALPHABET[(n >> (18 - 6 * i) & 63) as usize] as char);
+            } else {
+                out.push('=');
+            }
+        }
+    }
+    out
+}
+
+fn sextet(c: u8) -> Result<u32, Error> {
+    match ALPHABET.iter().position(|a| *a == c) {
+        Some(i) => Ok(i as u32),
+        None => Err(Error::InvalidBase64),
+    }
+}
+
+//? Rejects bad characters, misplaced padding and nonzero leftover bits
+pub(crate) fn decode(s: &str) -> Result<Vec<u8>, Error> {
+    let bytes = s.as_bytes();
+    if !bytes.len().is_multiple_of(4) {
+        return Err(Error::InvalidBase64);
+    }
+    let mut out = Vec::with_capacity(bytes.len() / 4 * 3);
+    let quads = bytes.len() / 4;
+    for (q, quad) in bytes.chunks(4).enumerate() {
+        let pad = quad.iter().rev().take_while(|c| **c == b'=').count();
+        if pad > 2 || (pad > 0 && q + 1 != quads) {
+            return Err(Error::InvalidBase64);
+        }
+        let mut n = 0u32;
+        for c in &quad[..4 - pad] {
+            n = n << 6 | sextet(*c)?;
+        }
+        n <<= 6 * pad as u32;
+        let keep = 3 - pad;
+        if n & ((1 << (8 * pad)) - 1) != 0 {
+            return Err(Error::InvalidBase64);
+        }
+        out.extend_from_slice(&n.to_be_bytes()[1..1 + keep]);
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rfc4648_vectors() {
+        let vectors = [
+            ("", ""),
+            ("f", "Zg=="),
+            ("fo", "Zm8="),
+            ("foo", "Zm9v"),
+            ("foob", "Zm9vYg=="),
+            ("fooba", "Zm9vYmE="),
+            ("foobar", "Zm9vYmFy"),
+        ];
+        for (plain, encoded) in vectors {
+            assert_eq!(encode(plain.as_bytes()), encoded);
+            assert_eq!(decode(encoded).unwrap(), plain.as_bytes());
+        }
+    }
+
+    #[test]
+    fn test_rejects_malformed() {
+        for bad in ["Zg=", "Z===", "Zg==Zg==", "Zm9v!A==", "Zh==", "Zm9=v"] {
+            assert_eq!(decode(bad), Err(Error::InvalidBase64), "{}", bad);
+        }
+    }
+}
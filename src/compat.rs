@@ -0,0 +1,147 @@
+//? Byte layouts matching arkworks' CanonicalSerialize for BN254.
+//? Field elements are 32 little-endian bytes. Compressed G1 points are the
+//? affine x in that layout with flags in the top two bits of the last byte:
+//? bit 7 marks y as "negative" (y > -y) and bit 6 marks infinity.
+
+use crate::error::Error;
+use crate::fp::Fp;
+use crate::g1::{G1, curve_rhs};
+
+const Y_IS_NEGATIVE: u8 = 1 << 7;
+const POINT_AT_INFINITY: u8 = 1 << 6;
+
+impl Fp {
+    pub fn to_ark_bytes(&self) -> [u8; 32] {
+        let mut out = self.to_bytes_be();
+        out.reverse();
+        out
+    }
+
+    pub fn from_ark_bytes(bytes: &[u8; 32]) -> Result<Fp, Error> {
+        let mut be = *bytes;
+        be.reverse();
+        if !Fp::is_canonical_bytes(&be) {
+            return Err(Error::NonCanonical);
+        }
+        Ok(Fp::from_bytes_unchecked(&be))
+    }
+}
+
+//? arkworks calls y negative when it is the larger of y and -y
+fn y_is_negative(y: &Fp) -> bool {
+    y.n > (-y.clone()).n
+}
+
+impl G1 {
+    pub fn to_ark_compressed(&self) -> [u8; 32] {
+        if self.is_infinity() {
+            let mut out = [0u8; 32];
+            out[31] = POINT_AT_INFINITY;
+            return out;
+        }
+        let (x, y) = self.to_affine();
+        let mut out = x.to_ark_bytes();
+        if y_is_negative(&y) {
+            out[31] |= Y_IS_NEGATIVE;
+        }
+        out
+    }
+
+    pub fn from_ark_compressed(bytes: &[u8; 32]) -> Result<G1, Error> {
+        let flags = bytes[31] & (Y_IS_NEGATIVE | POINT_AT_INFINITY);
+        if flags == Y_IS_NEGATIVE | POINT_AT_INFINITY {
+            return Err(Error::InvalidFlags);
+        }
+        let mut x_bytes = *bytes;
+        x_bytes[31] &= !(Y_IS_NEGATIVE | POINT_AT_INFINITY);
+        // Stricter than arkworks, as in from_bytes_compressed: infinity has one encoding
+        if flags == POINT_AT_INFINITY {
+            if x_bytes.iter().any(|b| *b != 0) {
+                return Err(Error::NonCanonical);
+            }
+            return Ok(G1::infinity());
+        }
+        let x = Fp::from_ark_bytes(&x_bytes)?;
+
+        let mut y = curve_rhs(&x).sqrt().ok_or(Error::NotOnCurve)?;
+        if y_is_negative(&y) != (flags == Y_IS_NEGATIVE) {
+            y = -y;
+        }
+        Ok(G1 { x, y, z: Fp::one() })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fr::Fr;
+
+    fn hex32(s: &str) -> [u8; 32] {
+        let mut out = [0u8; 32];
+        for (i, byte) in out.iter_mut().enumerate() {
+            *byte = u8::from_str_radix(&s[2 * i..2 * i + 2], 16).unwrap();
+        }
+        out
+    }
+
+    #[test]
+    fn test_fp_ark_bytes() {
+        let one = hex32("0100000000000000000000000000000000000000000000000000000000000000");
+        assert_eq!(Fp::one().to_ark_bytes(), one);
+        assert_eq!(Fp::from_ark_bytes(&one).unwrap(), Fp::one());
+
+        // p - 1
+        let minus_one = hex32("46fd7cd8168c203c8dca7168916a81975d588181b64550b829a031e1724e6430");
+        assert_eq!((-Fp::one()).to_ark_bytes(), minus_one);
+        assert_eq!(Fp::from_ark_bytes(&minus_one).unwrap(), -Fp::one());
+
+        // p itself is rejected
+        let p = hex32("47fd7cd8168c203c8dca7168916a81975d588181b64550b829a031e1724e6430");
+        assert_eq!(Fp::from_ark_bytes(&p), Err(Error::NonCanonical));
+    }
+
+    #[test]
+    fn test_g1_ark_compressed_vectors() {
+        let g = G1::generator();
+        let g_bytes = hex32("0100000000000000000000000000000000000000000000000000000000000000");
+        let neg_g_bytes = hex32("0100000000000000000000000000000000000000000000000000000000000080");
+        let inf_bytes = hex32("0000000000000000000000000000000000000000000000000000000000000040");
+
+        assert_eq!(g.to_ark_compressed(), g_bytes);
+        assert_eq!(g.neg().to_ark_compressed(), neg_g_bytes);
+        assert_eq!(G1::infinity().to_ark_compressed(), inf_bytes);
+
+        assert!(G1::from_ark_compressed(&g_bytes).unwrap().eq_projective(&g));
+        assert!(
+            G1::from_ark_compressed(&neg_g_bytes)
+                .unwrap()
+                .eq_projective(&g.neg())
+        );
+        assert!(G1::from_ark_compressed(&inf_bytes).unwrap().is_infinity());
+    }
+
+    #[test]
+    fn test_g1_ark_compressed_round_trip_and_rejects() {
+        for k in [2u128, 3, 1 << 100] {
+            let p = G1::generator().mul(&Fr::from_u128(k));
+            let decoded = G1::from_ark_compressed(&p.to_ark_compressed()).unwrap();
+            assert!(decoded.eq_projective(&p));
+        }
+
+        let both_flags = hex32("01000000000000000000000000000000000000000000000000000000000000c0");
+        assert_eq!(
+            G1::from_ark_compressed(&both_flags),
+            Err(Error::InvalidFlags)
+        );
+        let inf = G1::infinity().to_ark_compressed();
+        assert!(G1::from_ark_compressed(&inf).unwrap().is_infinity());
+        let mut inf_with_x = inf;
+        inf_with_x[0] = 1;
+        assert_eq!(
+            G1::from_ark_compressed(&inf_with_x),
+            Err(Error::NonCanonical)
+        );
+        // x = 0 gives y^2 = 3, and 3 is not a square mod p
+        assert_eq!(G1::from_ark_compressed(&[0u8; 32]), Err(Error::NotOnCurve));
+    }
+}
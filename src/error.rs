@@ -0,0 +1,32 @@
+use std::fmt;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Error {
+    LengthMismatch { left: usize, right: usize },
+    InvalidLength,
+    NonCanonical,
+    InvalidFlags,
+    NotOnCurve,
+    InvalidBase64,
+    InvalidDomainSize,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::LengthMismatch { left, right } => {
+                write!(f, "length mismatch: {} vs {}", left, right)
+            }
+            Error::InvalidLength => write!(f, "invalid encoding length"),
+            Error::NonCanonical => write!(f, "non-canonical field element"),
+            Error::InvalidFlags => write!(f, "invalid point flags"),
+            Error::NotOnCurve => write!(f, "point is not on the curve"),
+            Error::InvalidBase64 => write!(f, "malformed base64"),
+            Error::InvalidDomainSize => {
+                write!(f, "domain size must be a power of two dividing r - 1")
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
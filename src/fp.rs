@@ -1,14 +1,48 @@
+use crate::error::Error;
 use lazy_static::lazy_static;
 use num_bigint::{BigInt, BigUint, Sign};
-use num_traits::{One, Zero};
-use std::ops::{Add, Mul, Neg, Sub};
+use num_traits::{One, ToPrimitive, Zero};
+use std::hash::{Hash, Hasher};
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
 
 lazy_static! {
     static ref P: BigUint = BigUint::parse_bytes(
-        b"21888242871839275222246405745257275088548364400416034343698204186575808495617",
+        b"21888242871839275222246405745257275088696311157297823662689037894645226208583",
         10
     )
     .unwrap();
+
+    //? Prime factorization of P - 1 as (prime, exponent) pairs
+    static ref P_MINUS_1_FACTORS: Vec<(BigUint, u32)> = [
+        ("2", 1),
+        ("3", 2),
+        ("13", 1),
+        ("29", 1),
+        ("67", 1),
+        ("229", 1),
+        ("311", 1),
+        ("983", 1),
+        ("11003", 1),
+        ("405928799", 1),
+        ("11465965001", 1),
+        ("13427688667394608761327070753331941386769", 1),
+    ]
+    .iter()
+    .map(|(q, e)| (BigUint::parse_bytes(q.as_bytes(), 10).unwrap(), *e))
+    .collect();
+
+    //? p - 2 in 4-bit windows, most significant first, for inv_fermat. A NAF
+    //? would need self^-1 for its negative digits, so unsigned windows it is.
+    static ref P_MINUS_2_WINDOWS: Vec<usize> = {
+        let e = &*P - 2u32;
+        (0..e.bits().div_ceil(4))
+            .rev()
+            .map(|w| (0..4).fold(0usize, |acc, b| acc | ((e.bit(4 * w + b) as usize) << b)))
+            .collect()
+    };
+
+    //? Cached one; BigUint has no const constructor for it, unlike Fp::ZERO
+    pub static ref ONE: Fp = Fp::one();
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -17,21 +51,41 @@ pub struct Fp {
 }
 
 impl Fp {
+    pub const ZERO: Fp = Fp { n: BigUint::ZERO };
+
+    pub fn modulus() -> &'static BigUint {
+        &P
+    }
+
     pub fn new(n: BigUint) -> Self {
         let n = n % &*P;
         Fp { n }
     }
 
     pub fn zero() -> Self {
-        Fp { n: BigUint::zero() }
+        Fp::ZERO
     }
 
     pub fn one() -> Self {
         Fp { n: BigUint::one() }
     }
 
+    pub fn to_bigint(&self) -> BigInt {
+        BigInt::from(self.n.clone())
+    }
+
+    //? Reduces a signed integer into the field, mapping negatives to P - |b|
+    pub fn from_bigint(b: &BigInt) -> Self {
+        let p = BigInt::from(P.clone());
+        let mut r = b % &p;
+        if r.sign() == Sign::Minus {
+            r += p;
+        }
+        Fp::new(r.to_biguint().unwrap())
+    }
+
     pub fn inv(&self) -> Self {
-        let mut a = BigInt::from(self.n.clone());
+        let mut a = self.to_bigint();
         let mut m = BigInt::from(P.clone());
         let mut x0 = BigInt::zero();
         let mut x1 = BigInt::one();
@@ -50,16 +104,373 @@ impl Fp {
             x1 = t;
         }
 
-        if x1.sign() == Sign::Minus {
-            x1 += BigInt::from(P.clone());
+        Fp::from_bigint(&x1)
+    }
+    //? 2*x as a shift with at most one subtraction of P
+    pub fn double(&self) -> Self {
+        let d: BigUint = &self.n << 1;
+        if d >= *P {
+            Fp { n: d - &*P }
+        } else {
+            Fp { n: d }
         }
+    }
 
-        Fp::new(x1.to_biguint().unwrap())
+    pub fn triple(&self) -> Self {
+        self.double() + self.clone()
     }
+
     pub fn pow(&self, exp: &BigUint) -> Self {
-        Fp::new(self.n.modpow(exp, &*P))
+        Fp::new(self.n.modpow(exp, &P))
+    }
+
+    //? self^(p-2) over the precomputed windows of p - 2: 15 table products,
+    //? then 4 squarings and one product per window. Zero maps to zero.
+    pub fn inv_fermat(&self) -> Self {
+        let mut table = Vec::with_capacity(16);
+        table.push(Fp::one());
+        for i in 1..16 {
+            table.push(&table[i - 1] * self);
+        }
+        let mut acc = Fp::one();
+        for digit in P_MINUS_2_WINDOWS.iter() {
+            for _ in 0..4 {
+                acc = &acc * &acc;
+            }
+            if *digit != 0 {
+                acc *= &table[*digit];
+            }
+        }
+        acc
+    }
+
+    //? Inversion for secret values: inv_fermat's squarings and products are
+    //? fixed by p alone, unlike the input-dependent steps of Euclid in inv.
+    //? As with G1::mul_ct, BigUint arithmetic itself is not constant-time.
+    pub fn inv_ct(&self) -> Self {
+        self.inv_fermat()
+    }
+
+    //? Integer division of the canonical representative by d, not field
+    //? division: returns (floor(n / d), n mod d). Panics if d is zero.
+    pub fn div_rem_u64(&self, d: u64) -> (Fp, u64) {
+        assert!(d != 0, "division by zero");
+        let d = BigUint::from(d);
+        let q = &self.n / &d;
+        let r = &self.n % &d;
+        (Fp { n: q }, r.to_u64().unwrap())
+    }
+
+    //? Approximate magnitude of the canonical integer, for logs and metrics only.
+    //? Keeps ~53 significant bits, so distinct elements can map to the same f64.
+    pub fn to_f64_lossy(&self) -> f64 {
+        self.n.to_f64().unwrap_or(f64::INFINITY)
+    }
+
+    //? The canonical square root: of the two roots, the one r <= (p-1)/2.
+    //? None for non-residues.
+    pub fn sqrt(&self) -> Option<Self> {
+        self.sqrt_both().map(|(r, _)| r)
+    }
+
+    //? Both square roots, canonical (r <= (p-1)/2) first; p = 3 mod 4, so one of
+    //? them is self^((p+1)/4). Zero gives (0, 0).
+    pub fn sqrt_both(&self) -> Option<(Self, Self)> {
+        let exp = (&*P + BigUint::one()) >> 2;
+        let root = self.pow(&exp);
+        if root.clone() * root.clone() != *self {
+            return None;
+        }
+        let other = -root.clone();
+        if root.n <= other.n {
+            Some((root, other))
+        } else {
+            Some((other, root))
+        }
+    }
+
+    pub fn to_bytes_be(&self) -> [u8; 32] {
+        let raw = self.n.to_bytes_be();
+        let mut out = [0u8; 32];
+        out[32 - raw.len()..].copy_from_slice(&raw);
+        out
+    }
+
+    //? Big-endian parse for callers that already checked canonicality
+    pub fn from_bytes_unchecked(bytes: &[u8; 32]) -> Self {
+        debug_assert!(Fp::is_canonical_bytes(bytes), "non-canonical Fp encoding");
+        Fp {
+            n: BigUint::from_bytes_be(bytes),
+        }
+    }
+
+    //? Zero test that ORs four fixed limbs instead of branching on the value.
+    //? BigUint storage is itself variable-length, so only the check is branch-free.
+    pub fn ct_is_zero(&self) -> bool {
+        let acc = self.to_limbs().iter().fold(0u64, |acc, limb| acc | limb);
+        ((acc | acc.wrapping_neg()) >> 63) == 0
+    }
+
+    //? Little-endian u64 limbs, always four of them; from_limbs does not reduce
+    pub(crate) fn to_limbs(&self) -> [u64; 4] {
+        let mut limbs = [0u64; 4];
+        for (limb, digit) in limbs.iter_mut().zip(self.n.iter_u64_digits()) {
+            *limb = digit;
+        }
+        limbs
+    }
+
+    pub(crate) fn from_limbs(limbs: [u64; 4]) -> Fp {
+        let n = limbs
+            .iter()
+            .rev()
+            .fold(BigUint::zero(), |acc, limb| (acc << 64) + *limb);
+        Fp { n }
+    }
+
+    //? Returns table[index] after masking in every entry, so the scan does not
+    //? depend on index. Out-of-range indices yield zero.
+    pub fn ct_lookup(table: &[Fp], index: usize) -> Fp {
+        let mut acc = [0u64; 4];
+        for (i, entry) in table.iter().enumerate() {
+            let diff = (i ^ index) as u64;
+            let eq = 1 ^ ((diff | diff.wrapping_neg()) >> 63);
+            let mask = eq.wrapping_neg();
+            for (a, limb) in acc.iter_mut().zip(entry.to_limbs()) {
+                *a |= limb & mask;
+            }
+        }
+        Fp::from_limbs(acc)
+    }
+
+    //? Sum of a_i * b_i, accumulated unreduced and reduced once at the end
+    pub fn sum_of_products(a: &[Fp], b: &[Fp]) -> Result<Fp, Error> {
+        if a.len() != b.len() {
+            return Err(Error::LengthMismatch {
+                left: a.len(),
+                right: b.len(),
+            });
+        }
+        let mut acc = BigUint::zero();
+        for (x, y) in a.iter().zip(b) {
+            acc += &x.n * &y.n;
+        }
+        Ok(Fp::new(acc))
+    }
+
+    //? Smallest k > 0 with self^k = 1, found by stripping prime factors off P - 1
+    pub fn multiplicative_order(&self) -> BigUint {
+        if self.n.is_zero() {
+            panic!("Multiplicative order does not exist for zero");
+        }
+        let mut order = &*P - BigUint::one();
+        for (q, e) in P_MINUS_1_FACTORS.iter() {
+            for _ in 0..*e {
+                let candidate = &order / q;
+                if self.pow(&candidate) == Fp::one() {
+                    order = candidate;
+                } else {
+                    break;
+                }
+            }
+        }
+        order
+    }
+
+    //? Montgomery's trick: one inversion plus 3(n-1) multiplications.
+    //? Zeros are skipped and come back as zero instead of panicking like inv().
+    pub fn batch_inverse(values: &[Fp]) -> Vec<Fp> {
+        let mut prefix = Vec::with_capacity(values.len());
+        let mut acc = Fp::one();
+        for v in values {
+            prefix.push(acc.clone());
+            if !v.n.is_zero() {
+                acc *= v;
+            }
+        }
+        let mut inv = acc.inv();
+        let mut out = vec![Fp::zero(); values.len()];
+        for i in (0..values.len()).rev() {
+            if values[i].n.is_zero() {
+                continue;
+            }
+            out[i] = inv.clone() * prefix[i].clone();
+            inv *= &values[i];
+        }
+        out
+    }
+
+    //? Raises every base to the same exponent, walking the exponent bits once
+    pub fn batch_pow(bases: &[Fp], exp: &BigUint) -> Vec<Fp> {
+        let mut acc = vec![Fp::one(); bases.len()];
+        for i in (0..exp.bits()).rev() {
+            let bit = exp.bit(i);
+            for (a, b) in acc.iter_mut().zip(bases) {
+                *a = a.clone() * a.clone();
+                if bit {
+                    *a = a.clone() * b.clone();
+                }
+            }
+        }
+        acc
+    }
+
+    //? All 254 bits of the canonical value, most significant first, leading
+    //? zeros included, so a ladder over them runs the same number of steps
+    pub fn to_ladder_bits(&self) -> [bool; 254] {
+        let mut bits = [false; 254];
+        for (i, bit) in bits.iter_mut().enumerate() {
+            *bit = self.n.bit(253 - i as u64);
+        }
+        bits
+    }
+
+    //? Number of bits any integer below 2^capacity fits into without reduction
+    pub fn capacity() -> usize {
+        P.bits() as usize - 1
+    }
+
+    pub fn pow_u64(&self, exp: u64) -> Self {
+        self.pow(&BigUint::from(exp))
+    }
+
+    //? Checks that big-endian bytes encode a value below P without building an Fp
+    pub fn is_canonical_bytes(bytes: &[u8; 32]) -> bool {
+        BigUint::from_bytes_be(bytes) < *P
+    }
+}
+//? Z_H(x) = x^n - 1, the vanishing polynomial of the order-n subgroup
+pub fn vanishing_eval(n: u64, x: &Fp) -> Fp {
+    x.pow_u64(n) - Fp::one()
+}
+
+//? L_i(x) = prod_{j != i} (x - x_j) / (x_i - x_j) for distinct domain points x_i
+pub fn lagrange_coefficients(domain: &[Fp], x: &Fp) -> Vec<Fp> {
+    if let Some(k) = domain.iter().position(|d| d == x) {
+        return (0..domain.len())
+            .map(|i| if i == k { Fp::one() } else { Fp::zero() })
+            .collect();
+    }
+
+    let l_x = domain
+        .iter()
+        .fold(Fp::one(), |acc, d| acc * (x.clone() - d.clone()));
+    let denominators: Vec<Fp> = domain
+        .iter()
+        .enumerate()
+        .map(|(i, xi)| {
+            let w = domain
+                .iter()
+                .enumerate()
+                .filter(|(j, _)| *j != i)
+                .fold(Fp::one(), |acc, (_, xj)| acc * (xi.clone() - xj.clone()));
+            w * (x.clone() - xi.clone())
+        })
+        .collect();
+
+    Fp::batch_inverse(&denominators)
+        .into_iter()
+        .map(|d_inv| l_x.clone() * d_inv)
+        .collect()
+}
+
+//? LEB128 element count followed by 32 big-endian bytes per element
+pub fn serialize_fp_vec(v: &[Fp]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(10 + 32 * v.len());
+    let mut len = v.len() as u64;
+    loop {
+        let byte = (len & 0x7f) as u8;
+        len >>= 7;
+        if len == 0 {
+            out.push(byte);
+            break;
+        }
+        out.push(byte | 0x80);
+    }
+    for x in v {
+        out.extend_from_slice(&x.to_bytes_be());
+    }
+    out
+}
+
+//? Rejects non-minimal counts (a trailing 0x00 group after the first byte),
+//? so each vector has exactly one encoding
+pub fn deserialize_fp_vec(data: &[u8]) -> Result<Vec<Fp>, Error> {
+    let mut len: u64 = 0;
+    let mut pos = 0;
+    loop {
+        let byte = *data.get(pos).ok_or(Error::InvalidLength)?;
+        if pos == 9 && byte > 1 {
+            return Err(Error::InvalidLength);
+        }
+        if pos > 0 && byte == 0 {
+            return Err(Error::NonCanonical);
+        }
+        len |= ((byte & 0x7f) as u64) << (7 * pos);
+        pos += 1;
+        if byte & 0x80 == 0 {
+            break;
+        }
+    }
+
+    let body = &data[pos..];
+    if (body.len() as u64) != len.saturating_mul(32) {
+        return Err(Error::InvalidLength);
+    }
+    body.chunks_exact(32)
+        .map(|chunk| {
+            let bytes: &[u8; 32] = chunk.try_into().unwrap();
+            if Fp::is_canonical_bytes(bytes) {
+                Ok(Fp::from_bytes_unchecked(bytes))
+            } else {
+                Err(Error::NonCanonical)
+            }
+        })
+        .collect()
+}
+
+//? Packs bits LSB-first into Fp::capacity()-sized chunks, one element per chunk
+pub fn pack_bits_chunked(bits: &[bool]) -> Vec<Fp> {
+    bits.chunks(Fp::capacity())
+        .map(|chunk| {
+            let mut n = BigUint::zero();
+            for (i, bit) in chunk.iter().enumerate() {
+                if *bit {
+                    n.set_bit(i as u64, true);
+                }
+            }
+            Fp { n }
+        })
+        .collect()
+}
+
+//? Inverse of pack_bits_chunked; the original bit count is needed to drop padding
+pub fn unpack_bits_chunked(elements: &[Fp], len: usize) -> Vec<bool> {
+    let capacity = Fp::capacity();
+    let mut bits = Vec::with_capacity(len);
+    for e in elements {
+        for i in 0..capacity {
+            if bits.len() == len {
+                return bits;
+            }
+            bits.push(e.n.bit(i as u64));
+        }
     }
+    bits
 }
+
+//? Free-function form of Fp::sum_of_products
+pub fn inner_product(a: &[Fp], b: &[Fp]) -> Result<Fp, Error> {
+    Fp::sum_of_products(a, b)
+}
+
+//? Multiplies into a's buffer and reduces the product once, skipping the
+//? extra pass through Fp::new. Every Fp multiply operator goes through here.
+fn mul_reduce(a: &mut BigUint, b: &BigUint) {
+    *a *= b;
+    *a %= &*P;
+}
+
 // Operator overloading
 impl Add for Fp {
     type Output = Fp;
@@ -82,8 +493,80 @@ impl Sub for Fp {
 
 impl Mul for Fp {
     type Output = Fp;
-    fn mul(self, rhs: Fp) -> Fp {
-        Fp::new(self.n * rhs.n)
+    fn mul(mut self, rhs: Fp) -> Fp {
+        mul_reduce(&mut self.n, &rhs.n);
+        self
+    }
+}
+
+//? Borrowing forms, so hot formulas need not clone their operands
+impl Add<&Fp> for &Fp {
+    type Output = Fp;
+    fn add(self, rhs: &Fp) -> Fp {
+        let mut res = self.clone();
+        res += rhs;
+        res
+    }
+}
+
+impl Sub<&Fp> for &Fp {
+    type Output = Fp;
+    fn sub(self, rhs: &Fp) -> Fp {
+        let mut res = self.clone();
+        res -= rhs;
+        res
+    }
+}
+
+impl Mul<&Fp> for &Fp {
+    type Output = Fp;
+    fn mul(self, rhs: &Fp) -> Fp {
+        let mut n = self.n.clone();
+        mul_reduce(&mut n, &rhs.n);
+        Fp { n }
+    }
+}
+
+//? Both operands are reduced, so one conditional correction suffices
+impl AddAssign<&Fp> for Fp {
+    fn add_assign(&mut self, rhs: &Fp) {
+        self.n += &rhs.n;
+        if self.n >= *P {
+            self.n -= &*P;
+        }
+    }
+}
+
+impl SubAssign<&Fp> for Fp {
+    fn sub_assign(&mut self, rhs: &Fp) {
+        if self.n < rhs.n {
+            self.n += &*P;
+        }
+        self.n -= &rhs.n;
+    }
+}
+
+impl MulAssign<&Fp> for Fp {
+    fn mul_assign(&mut self, rhs: &Fp) {
+        mul_reduce(&mut self.n, &rhs.n);
+    }
+}
+
+impl AddAssign for Fp {
+    fn add_assign(&mut self, rhs: Fp) {
+        *self += &rhs;
+    }
+}
+
+impl SubAssign for Fp {
+    fn sub_assign(&mut self, rhs: Fp) {
+        *self -= &rhs;
+    }
+}
+
+impl MulAssign for Fp {
+    fn mul_assign(&mut self, rhs: Fp) {
+        *self *= &rhs;
     }
 }
 
@@ -98,6 +581,34 @@ impl Neg for Fp {
     }
 }
 
+//? Hashes the canonical big-endian bytes, never BigUint's internal limbs
+impl Hash for Fp {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.to_bytes_be().hash(state);
+    }
+}
+
+//? Lets Fp stand in for generic num_traits code; elements are always reduced
+impl Zero for Fp {
+    fn zero() -> Self {
+        Fp::ZERO
+    }
+
+    fn is_zero(&self) -> bool {
+        self.n.is_zero()
+    }
+}
+
+impl One for Fp {
+    fn one() -> Self {
+        ONE.clone()
+    }
+
+    fn is_one(&self) -> bool {
+        self.n.is_one()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -138,4 +649,531 @@ mod tests {
         assert_eq!(a.clone() * b.clone(), Fp::new(150u32.to_biguint().unwrap()));
         assert_eq!(-a.clone(), Fp::new(&*P - 10u32.to_biguint().unwrap()));
     }
+
+    #[test]
+    fn test_reference_and_assign_ops() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..10 {
+            let a = -Fp::new(rng.gen_range(0..u64::MAX).to_biguint().unwrap());
+            let b = Fp::new(rng.gen_range(0..u64::MAX).to_biguint().unwrap());
+            assert_eq!(&a + &b, a.clone() + b.clone());
+            assert_eq!(&a - &b, a.clone() - b.clone());
+            assert_eq!(&b - &a, b.clone() - a.clone());
+            assert_eq!(&a * &b, a.clone() * b.clone());
+
+            let mut c = a.clone();
+            c += &b;
+            assert_eq!(c, a.clone() + b.clone());
+            c -= b.clone();
+            assert_eq!(c, a);
+            c *= &b;
+            assert_eq!(c, a.clone() * b.clone());
+        }
+    }
+
+    #[test]
+    fn test_batch_inverse() {
+        let mut rng = rand::thread_rng();
+        let values: Vec<Fp> = (0..8)
+            .map(|_| -Fp::new(rng.gen_range(1..u64::MAX).to_biguint().unwrap()))
+            .collect();
+        let inverses = Fp::batch_inverse(&values);
+        for (v, inv) in values.iter().zip(&inverses) {
+            assert_eq!(*inv, v.inv());
+        }
+
+        let with_zeros = vec![Fp::zero(), values[0].clone(), Fp::zero(), values[1].clone()];
+        let inverses = Fp::batch_inverse(&with_zeros);
+        assert_eq!(inverses[0], Fp::zero());
+        assert_eq!(inverses[1], values[0].inv());
+        assert_eq!(inverses[2], Fp::zero());
+        assert_eq!(inverses[3], values[1].inv());
+        assert!(Fp::batch_inverse(&[]).is_empty());
+        assert_eq!(Fp::batch_inverse(&[Fp::zero()]), vec![Fp::zero()]);
+    }
+
+    #[test]
+    fn test_num_traits() {
+        fn sum<T: Zero + Clone>(xs: &[T]) -> T {
+            xs.iter().cloned().fold(T::zero(), |acc, x| acc + x)
+        }
+        assert_eq!(<Fp as num_traits::Zero>::zero(), Fp::zero());
+        assert_eq!(<Fp as One>::one(), Fp::one());
+        assert!(Zero::is_zero(&Fp::ZERO));
+        assert!(One::is_one(&Fp::one()));
+        assert!(!One::is_one(&-Fp::one()));
+        let xs = [Fp::one(), Fp::one(), Fp::new(3u32.into())];
+        assert_eq!(sum(&xs), Fp::new(5u32.into()));
+    }
+
+    #[test]
+    fn test_hash_matches_value() {
+        use std::collections::hash_map::DefaultHasher;
+        fn hash_of(a: &Fp) -> u64 {
+            let mut h = DefaultHasher::new();
+            a.hash(&mut h);
+            h.finish()
+        }
+        let five = Fp::new(5u32.into());
+        let via_wrap = Fp::new(&*P + BigUint::from(5u32));
+        let via_bigint = Fp::from_bigint(&BigInt::from(-2)) + Fp::new(7u32.into());
+        let via_bytes = Fp::from_bytes_unchecked(&five.to_bytes_be());
+        for other in [&via_wrap, &via_bigint, &via_bytes] {
+            assert_eq!(*other, five);
+            assert_eq!(hash_of(other), hash_of(&five));
+        }
+        assert_ne!(hash_of(&five), hash_of(&Fp::new(6u32.into())));
+    }
+
+    #[test]
+    fn test_div_rem_u64() {
+        let mut rng = rand::thread_rng();
+        for d in [1u64, 2, 10, 1 << 32, u64::MAX] {
+            let a = -Fp::new(rng.gen_range(0..u64::MAX).to_biguint().unwrap());
+            let (q, r) = a.div_rem_u64(d);
+            assert_eq!(q.n, &a.n / d);
+            assert_eq!(BigUint::from(r), &a.n % d);
+            assert_eq!(q.n * d + r, a.n);
+        }
+        // Integer, not field, division: 7 / 2 is 3, not 7 * 2^-1
+        assert_eq!(
+            Fp::new(7u32.into()).div_rem_u64(2),
+            (Fp::new(3u32.into()), 1)
+        );
+    }
+
+    #[test]
+    fn test_to_f64_lossy() {
+        assert_eq!(Fp::zero().to_f64_lossy(), 0.0);
+        assert_eq!(Fp::new(12345u32.into()).to_f64_lossy(), 12345.0);
+        let samples = [
+            Fp::one(),
+            Fp::new(1000u32.into()),
+            Fp::new(BigUint::one() << 100),
+            Fp::new(BigUint::one() << 200),
+            -Fp::one(),
+        ];
+        for pair in samples.windows(2) {
+            assert!(pair[0].to_f64_lossy() < pair[1].to_f64_lossy());
+        }
+    }
+
+    #[test]
+    fn test_sqrt() {
+        let a = Fp::new(123456789u64.to_biguint().unwrap());
+        let sq = a.clone() * a.clone();
+        let root = sq.sqrt().unwrap();
+        assert!(root == a || root == -a);
+        assert_eq!(Fp::zero().sqrt(), Some(Fp::zero()));
+        assert_eq!(Fp::zero().sqrt_both(), Some((Fp::zero(), Fp::zero())));
+        // -1 is a non-residue since p = 3 mod 4, and so is 3
+        assert_eq!((-Fp::one()).sqrt(), None);
+        assert_eq!(Fp::new(3u32.into()).sqrt(), None);
+    }
+
+    #[test]
+    fn test_sqrt_canonical_vectors() {
+        let half = (&*P - BigUint::one()) >> 1;
+        let vectors = [
+            (4u32, "2"),
+            (9, "3"),
+            (
+                2,
+                "3969792565221544645472939191694882283483352126195956956354061729942568608776",
+            ),
+            (
+                7,
+                "8464338893679097646088152975481412295781603611608381993215323776047827464806",
+            ),
+        ];
+        for (a, root) in vectors {
+            let a = Fp::new(a.into());
+            let root = Fp::new(BigUint::parse_bytes(root.as_bytes(), 10).unwrap());
+            assert_eq!(a.sqrt(), Some(root.clone()));
+            assert_eq!(a.sqrt_both(), Some((root.clone(), -root.clone())));
+            assert!(root.n <= half);
+        }
+        assert_eq!(Fp::new(3u32.into()).sqrt_both(), None);
+    }
+
+    #[test]
+    fn test_bigint_conversion() {
+        assert_eq!(Fp::from_bigint(&BigInt::from(-1)), -Fp::one());
+        assert_eq!(Fp::from_bigint(&-Fp::one().to_bigint()), -Fp::one());
+
+        let mut rng = rand::thread_rng();
+        for _ in 0..10 {
+            let n: u64 = rng.gen_range(0..u64::MAX);
+            let a = Fp::new(n.to_biguint().unwrap());
+            assert_eq!(Fp::from_bigint(&a.to_bigint()), a);
+        }
+
+        let p_plus_7 = BigInt::from(P.clone()) + 7;
+        assert_eq!(
+            Fp::from_bigint(&p_plus_7),
+            Fp::new(7u32.to_biguint().unwrap())
+        );
+    }
+
+    #[test]
+    #[allow(clippy::assign_op_pattern)]
+    fn test_inner_product() {
+        let mut rng = rand::thread_rng();
+        let mut rand_fp = || -Fp::new(rng.gen_range(1..u64::MAX).to_biguint().unwrap());
+        let a: Vec<Fp> = (0..20).map(|_| rand_fp()).collect();
+        let b: Vec<Fp> = (0..20).map(|_| rand_fp()).collect();
+
+        let mut naive = Fp::zero();
+        for (x, y) in a.iter().zip(&b) {
+            naive = naive + x.clone() * y.clone();
+        }
+        assert_eq!(inner_product(&a, &b).unwrap(), naive);
+        assert_eq!(inner_product(&[], &[]).unwrap(), Fp::zero());
+        assert_eq!(
+            inner_product(&a, &b[..3]),
+            Err(Error::LengthMismatch { left: 20, right: 3 })
+        );
+    }
+
+    #[test]
+    fn test_ct_is_zero() {
+        let mut rng = rand::thread_rng();
+        assert!(Fp::zero().ct_is_zero());
+        assert!(!Fp::one().ct_is_zero());
+        assert!(!(-Fp::one()).ct_is_zero());
+        assert!((Fp::one() - Fp::one()).ct_is_zero());
+        for _ in 0..10 {
+            let a = Fp::new(rng.gen_range(0..4u64).to_biguint().unwrap());
+            assert_eq!(a.ct_is_zero(), a.n.is_zero());
+        }
+    }
+
+    #[test]
+    fn test_mul_reduce() {
+        let mut rng = rand::thread_rng();
+        let p_minus_1 = &*P - BigUint::one();
+        let mut a = p_minus_1.clone();
+        mul_reduce(&mut a, &p_minus_1);
+        assert_eq!(a, BigUint::one());
+        let mut zero = BigUint::zero();
+        mul_reduce(&mut zero, &p_minus_1);
+        assert!(zero.is_zero());
+        for _ in 0..10 {
+            let a = -Fp::new(rng.gen_range(1..u64::MAX).to_biguint().unwrap());
+            let b = -Fp::new(rng.gen_range(1..u64::MAX).to_biguint().unwrap());
+            let mut c = a.clone();
+            c *= &b;
+            assert_eq!(&a * &b, c);
+            assert_eq!(a.clone() * b.clone(), Fp::new(a.n * b.n));
+        }
+    }
+
+    #[test]
+    fn test_double_triple() {
+        let mut rng = rand::thread_rng();
+        let p_minus_1 = Fp::new(&*P - BigUint::one());
+        assert_eq!(p_minus_1.double(), p_minus_1.clone() + p_minus_1.clone());
+        assert_eq!(Fp::zero().triple(), Fp::zero());
+        for _ in 0..10 {
+            let a = -Fp::new(rng.gen_range(1..u64::MAX).to_biguint().unwrap());
+            assert_eq!(a.double(), a.clone() + a.clone());
+            assert_eq!(a.triple(), a.clone() + a.clone() + a.clone());
+        }
+    }
+
+    #[test]
+    fn test_vanishing_eval() {
+        // P - 1 = 2 * 3^2 * 13 * ..., so Fp has an order-n subgroup for n = 2 * 9 * 13
+        let n = 234u64;
+        let three = Fp::new(3u32.to_biguint().unwrap());
+        let omega = three.pow(&((&*P - BigUint::one()) / n));
+        assert_eq!(vanishing_eval(n, &omega), Fp::zero());
+        assert_eq!(vanishing_eval(n, &omega.pow_u64(5)), Fp::zero());
+        assert_eq!(vanishing_eval(2, &-Fp::one()), Fp::zero());
+        assert_eq!(vanishing_eval(n, &Fp::zero()), -Fp::one());
+        assert_ne!(vanishing_eval(n, &three), Fp::zero());
+    }
+
+    #[test]
+    fn test_lagrange_coefficients() {
+        let fp = |n: u64| Fp::new(n.to_biguint().unwrap());
+        // f(t) = 3t^3 + 2t + 7
+        let f = |t: &Fp| fp(3) * t.clone() * t.clone() * t.clone() + fp(2) * t.clone() + fp(7);
+        let domain: Vec<Fp> = (1..=4).map(fp).collect();
+        let values: Vec<Fp> = domain.iter().map(f).collect();
+
+        for x in [fp(10), -fp(5), fp(3)] {
+            let coeffs = lagrange_coefficients(&domain, &x);
+            let interpolated = coeffs
+                .iter()
+                .zip(&values)
+                .fold(Fp::zero(), |acc, (l, v)| acc + l.clone() * v.clone());
+            assert_eq!(interpolated, f(&x));
+        }
+    }
+
+    #[test]
+    fn test_fp_vec_serialization() {
+        let empty = serialize_fp_vec(&[]);
+        assert_eq!(empty, vec![0u8]);
+        assert_eq!(deserialize_fp_vec(&empty).unwrap(), vec![]);
+
+        let mut rng = rand::thread_rng();
+        let v: Vec<Fp> = (0..300)
+            .map(|_| -Fp::new(rng.gen_range(0..u64::MAX).to_biguint().unwrap()))
+            .collect();
+        let bytes = serialize_fp_vec(&v);
+        assert_eq!(&bytes[..2], &[0xac, 0x02]); // 300 as LEB128
+        assert_eq!(bytes.len(), 2 + 32 * 300);
+        assert_eq!(deserialize_fp_vec(&bytes).unwrap(), v);
+
+        assert_eq!(
+            deserialize_fp_vec(&bytes[..bytes.len() - 1]),
+            Err(Error::InvalidLength)
+        );
+        assert_eq!(deserialize_fp_vec(&[0x80]), Err(Error::InvalidLength));
+
+        // Zero and 300 padded with an empty high group
+        assert_eq!(deserialize_fp_vec(&[0x80, 0x00]), Err(Error::NonCanonical));
+        let mut padded = vec![0xac, 0x82, 0x00];
+        padded.extend_from_slice(&bytes[2..]);
+        assert_eq!(deserialize_fp_vec(&padded), Err(Error::NonCanonical));
+
+        let mut non_canonical = vec![1u8];
+        non_canonical.extend_from_slice(&to_be_32(&P));
+        assert_eq!(deserialize_fp_vec(&non_canonical), Err(Error::NonCanonical));
+    }
+
+    #[test]
+    fn test_batch_pow() {
+        let mut rng = rand::thread_rng();
+        let bases: Vec<Fp> = (0..10)
+            .map(|_| -Fp::new(rng.gen_range(0..u64::MAX).to_biguint().unwrap()))
+            .collect();
+        let legendre_exp = (&*P - BigUint::one()) >> 1;
+        for exp in [BigUint::zero(), BigUint::one(), legendre_exp] {
+            let batch = Fp::batch_pow(&bases, &exp);
+            for (b, r) in bases.iter().zip(&batch) {
+                assert_eq!(*r, b.pow(&exp));
+            }
+        }
+        assert!(Fp::batch_pow(&[], &BigUint::one()).is_empty());
+    }
+
+    #[test]
+    fn test_pack_bits_chunked() {
+        assert_eq!(Fp::capacity(), 253);
+        assert!(pack_bits_chunked(&[]).is_empty());
+
+        let mut rng = rand::thread_rng();
+        for len in [1usize, 253, 254, 1000] {
+            let bits: Vec<bool> = (0..len).map(|_| rng.gen_bool(0.5)).collect();
+            let packed = pack_bits_chunked(&bits);
+            assert_eq!(packed.len(), len.div_ceil(253));
+            assert_eq!(unpack_bits_chunked(&packed, len), bits);
+        }
+
+        let all_ones = pack_bits_chunked(&[true; 253]);
+        assert_eq!(all_ones[0].n, (BigUint::one() << 253) - BigUint::one());
+    }
+
+    #[test]
+    fn test_inv_fermat_matches_inv() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..10 {
+            let mut bytes = [0u8; 32];
+            rng.fill(&mut bytes);
+            let a = Fp::new(BigUint::from_bytes_be(&bytes));
+            if a.n.is_zero() {
+                continue;
+            }
+            assert_eq!(a.inv_fermat(), a.inv());
+        }
+        assert_eq!(Fp::one().inv_fermat(), Fp::one());
+        assert_eq!((-Fp::one()).inv_fermat(), -Fp::one());
+        assert_eq!(Fp::zero().inv_fermat(), Fp::zero());
+    }
+
+    #[test]
+    fn test_inv_ct() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..10 {
+            let a = Fp::new(rng.gen_range(1u64..u64::MAX).into()) * Fp::new(P.clone() - 5u32);
+            assert_eq!(a.inv_ct(), a.inv());
+            assert_eq!(a.inv_ct() * a, Fp::one());
+        }
+        assert_eq!(Fp::zero().inv_ct(), Fp::zero());
+    }
+
+    #[test]
+    fn test_ladder_bits() {
+        let mut rng = rand::thread_rng();
+        let mut bytes = [0u8; 32];
+        rng.fill(&mut bytes);
+        for a in [
+            Fp::new(BigUint::from_bytes_be(&bytes)),
+            -Fp::one(),
+            Fp::one(),
+            Fp::zero(),
+        ] {
+            let bits = a.to_ladder_bits();
+            assert_eq!(bits.len(), P.bits() as usize);
+            let value = bits.iter().fold(BigUint::zero(), |acc, b| {
+                (acc << 1) + BigUint::from(*b as u8)
+            });
+            assert_eq!(value, a.n);
+        }
+        assert!(Fp::one().to_ladder_bits()[253]);
+        assert!(Fp::one().to_ladder_bits()[..253].iter().all(|b| !b));
+    }
+
+    #[test]
+    fn test_ct_lookup() {
+        let mut rng = rand::thread_rng();
+        let table: Vec<Fp> = (0..16)
+            .map(|_| -Fp::new(rng.gen_range(0..u64::MAX).to_biguint().unwrap()))
+            .collect();
+        for (i, entry) in table.iter().enumerate() {
+            assert_eq!(Fp::ct_lookup(&table, i), *entry);
+        }
+        assert_eq!(Fp::ct_lookup(&table, 16), Fp::zero());
+        assert_eq!(Fp::ct_lookup(&[], 0), Fp::zero());
+    }
+
+    #[test]
+    fn test_zero_one_constants() {
+        assert_eq!(Fp::ZERO, Fp::zero());
+        assert_eq!(*ONE, Fp::one());
+        assert_ne!(Fp::ZERO, *ONE);
+        assert!(Fp::ZERO.n.is_zero());
+    }
+
+    #[test]
+    fn test_sum_of_products() {
+        let mut rng = rand::thread_rng();
+        let mut rand_fp = || -Fp::new(rng.gen_range(1..u64::MAX).to_biguint().unwrap());
+        let a: Vec<Fp> = (0..50).map(|_| rand_fp()).collect();
+        let b: Vec<Fp> = (0..50).map(|_| rand_fp()).collect();
+
+        let eager = a
+            .iter()
+            .zip(&b)
+            .fold(Fp::zero(), |acc, (x, y)| acc + x.clone() * y.clone());
+        assert_eq!(Fp::sum_of_products(&a, &b).unwrap(), eager);
+        assert!(Fp::sum_of_products(&a[1..], &b).is_err());
+    }
+
+    #[test]
+    fn test_multiplicative_order() {
+        let p_minus_1 = &*P - BigUint::one();
+        let product = P_MINUS_1_FACTORS
+            .iter()
+            .fold(BigUint::one(), |acc, (q, e)| acc * q.pow(*e));
+        assert_eq!(product, p_minus_1);
+
+        assert_eq!(Fp::one().multiplicative_order(), BigUint::one());
+        assert_eq!(
+            (-Fp::one()).multiplicative_order(),
+            2u32.to_biguint().unwrap()
+        );
+
+        // 3 generates the multiplicative group, so 3^((P-1)/n) has order n
+        let three = Fp::new(3u32.to_biguint().unwrap());
+        assert_eq!(three.multiplicative_order(), p_minus_1);
+        for n in [2u32, 9, 13 * 67, 2 * 983 * 11003] {
+            let n = n.to_biguint().unwrap();
+            let root = three.pow(&(&p_minus_1 / &n));
+            assert_eq!(root.multiplicative_order(), n);
+        }
+    }
+
+    // Sixteen 32-bit primes; their product exceeds (P - 1)^2
+    const CRT_PRIMES: [u64; 16] = [
+        4294967291, 4294967279, 4294967231, 4294967197, 4294967189, 4294967161, 4294967143,
+        4294967111, 4294967087, 4294967029, 4294966997, 4294966981, 4294966943, 4294966927,
+        4294966909, 4294966877,
+    ];
+
+    // Residue via byte-wise Horner in native integers, independent of BigUint division
+    fn residue(n: &BigUint, m: u64) -> u64 {
+        n.to_bytes_be()
+            .iter()
+            .fold(0u128, |r, b| (r * 256 + *b as u128) % m as u128) as u64
+    }
+
+    fn inv_mod_u64(a: u64, m: u64) -> u64 {
+        let (mut base, mut exp, mut acc) = (a as u128 % m as u128, m - 2, 1u128);
+        while exp > 0 {
+            if exp & 1 == 1 {
+                acc = acc * base % m as u128;
+            }
+            base = base * base % m as u128;
+            exp >>= 1;
+        }
+        acc as u64
+    }
+
+    // Chinese remainder reconstruction from residues modulo CRT_PRIMES
+    fn fp_from_residues(residues: &[u64]) -> Fp {
+        let modulus = CRT_PRIMES
+            .iter()
+            .fold(BigUint::one(), |acc, m| acc * BigUint::from(*m));
+        let mut x = BigUint::zero();
+        for (r, m) in residues.iter().zip(CRT_PRIMES.iter()) {
+            let m_i = &modulus / BigUint::from(*m);
+            let m_i_inv = inv_mod_u64(residue(&m_i, *m), *m);
+            x += BigUint::from(*r) * m_i * BigUint::from(m_i_inv);
+        }
+        Fp::new(x % modulus)
+    }
+
+    #[test]
+    fn test_fp_from_residues() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..10 {
+            let a = -Fp::new(rng.gen_range(1..u64::MAX).to_biguint().unwrap());
+            let b = -Fp::new(rng.gen_range(1..u64::MAX).to_biguint().unwrap());
+
+            let ra: Vec<u64> = CRT_PRIMES.iter().map(|m| residue(&a.n, *m)).collect();
+            assert_eq!(fp_from_residues(&ra), a);
+
+            // The unreduced product fits under the CRT modulus, so this checks Mul's reduction
+            let rab: Vec<u64> = CRT_PRIMES
+                .iter()
+                .map(|m| {
+                    ((residue(&a.n, *m) as u128 * residue(&b.n, *m) as u128) % *m as u128) as u64
+                })
+                .collect();
+            assert_eq!(fp_from_residues(&rab), a * b);
+        }
+    }
+
+    fn to_be_32(n: &BigUint) -> [u8; 32] {
+        let raw = n.to_bytes_be();
+        let mut out = [0u8; 32];
+        out[32 - raw.len()..].copy_from_slice(&raw);
+        out
+    }
+
+    #[test]
+    fn test_from_bytes_unchecked() {
+        let p_minus_1 = &*P - BigUint::one();
+        assert_eq!(Fp::from_bytes_unchecked(&to_be_32(&p_minus_1)), -Fp::one());
+        assert_eq!(Fp::from_bytes_unchecked(&[0u8; 32]), Fp::zero());
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "non-canonical Fp encoding")]
+    fn test_from_bytes_unchecked_rejects_non_canonical_in_debug() {
+        Fp::from_bytes_unchecked(&to_be_32(&P));
+    }
+
+    #[test]
+    fn test_is_canonical_bytes() {
+        assert!(Fp::is_canonical_bytes(&[0u8; 32]));
+        assert!(Fp::is_canonical_bytes(&to_be_32(&(&*P - BigUint::one()))));
+        assert!(!Fp::is_canonical_bytes(&to_be_32(&P)));
+        assert!(!Fp::is_canonical_bytes(&[0xff; 32]));
+    }
 }
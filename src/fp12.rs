@@ -0,0 +1,249 @@
+use crate::fp::Fp;
+use crate::fp2::Fp2;
+use crate::fp6::Fp6;
+use lazy_static::lazy_static;
+use num_bigint::BigUint;
+use num_traits::One;
+use std::ops::{Add, Mul, Neg, Sub};
+
+lazy_static! {
+    //? gamma_i = xi^(i(p-1)/6), so that (a * w^i)^p = conj(a) * gamma_i * w^i
+    static ref FROBENIUS_COEFFS: Vec<Fp2> = {
+        let xi = Fp2::new(Fp::new(9u32.into()), Fp::one());
+        let e = (Fp::modulus() - BigUint::one()) / 6u32;
+        (0..6u32).map(|i| xi.pow(&(&e * i))).collect()
+    };
+}
+
+//? c0 + c1*w with w^2 = v, i.e. w^6 = xi
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Fp12 {
+    pub c0: Fp6,
+    pub c1: Fp6,
+}
+
+impl Fp12 {
+    pub fn new(c0: Fp6, c1: Fp6) -> Self {
+        Fp12 { c0, c1 }
+    }
+
+    pub fn zero() -> Self {
+        Fp12 {
+            c0: Fp6::zero(),
+            c1: Fp6::zero(),
+        }
+    }
+
+    pub fn one() -> Self {
+        Fp12 {
+            c0: Fp6::one(),
+            c1: Fp6::zero(),
+        }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        *self == Fp12::zero()
+    }
+
+    pub fn is_one(&self) -> bool {
+        *self == Fp12::one()
+    }
+
+    //? The p^6-power Frobenius, w -> -w
+    pub fn conjugate(&self) -> Self {
+        Fp12 {
+            c0: self.c0.clone(),
+            c1: -self.c1.clone(),
+        }
+    }
+
+    //? Equals inv() only on the cyclotomic subgroup, e.g. pairing outputs,
+    //? where f^(p^6 + 1) = 1; for arbitrary elements use inv()
+    pub fn unitary_inverse(&self) -> Self {
+        self.conjugate()
+    }
+
+    //? (c0 - c1*w) / (c0^2 - v*c1^2)
+    pub fn inv(&self) -> Self {
+        let t = self.c0.clone() * self.c0.clone()
+            - (self.c1.clone() * self.c1.clone()).mul_by_nonresidue();
+        let t_inv = t.inv();
+        Fp12 {
+            c0: self.c0.clone() * t_inv.clone(),
+            c1: -(self.c1.clone() * t_inv),
+        }
+    }
+
+    //? a -> a^(p^power), applying the p-power map coefficient-wise in the w basis
+    pub fn frobenius_map(&self, power: usize) -> Self {
+        let mut res = self.clone();
+        for _ in 0..power {
+            let g = &*FROBENIUS_COEFFS;
+            res = Fp12 {
+                c0: Fp6::new(
+                    res.c0.c0.conjugate(),
+                    res.c0.c1.conjugate() * g[2].clone(),
+                    res.c0.c2.conjugate() * g[4].clone(),
+                ),
+                c1: Fp6::new(
+                    res.c1.c0.conjugate() * g[1].clone(),
+                    res.c1.c1.conjugate() * g[3].clone(),
+                    res.c1.c2.conjugate() * g[5].clone(),
+                ),
+            };
+        }
+        res
+    }
+
+    //? self * (c0 + c3*w + c4*v*w), the line shape of our D-type twist
+    pub fn mul_by_034(&self, c0: &Fp2, c3: &Fp2, c4: &Fp2) -> Self {
+        let a = Fp6::new(
+            self.c0.c0.clone() * c0.clone(),
+            self.c0.c1.clone() * c0.clone(),
+            self.c0.c2.clone() * c0.clone(),
+        );
+        let b = self.c1.mul_by_01(c3, c4);
+        let e = (self.c0.clone() + self.c1.clone()).mul_by_01(&(c0.clone() + c3.clone()), c4);
+        Fp12 {
+            c0: b.mul_by_nonresidue() + a.clone(),
+            c1: e - (a + b),
+        }
+    }
+
+    pub fn pow(&self, exp: &BigUint) -> Self {
+        let mut res = Fp12::one();
+        for i in (0..exp.bits()).rev() {
+            res = res.clone() * res;
+            if exp.bit(i) {
+                res = res * self.clone();
+            }
+        }
+        res
+    }
+}
+
+//? gamma_2 and gamma_3, used to apply the Frobenius to points on the twist
+pub(crate) fn twist_frobenius_coeffs() -> (Fp2, Fp2) {
+    (FROBENIUS_COEFFS[2].clone(), FROBENIUS_COEFFS[3].clone())
+}
+
+// Operator overloading
+impl Add for Fp12 {
+    type Output = Fp12;
+    fn add(self, rhs: Fp12) -> Fp12 {
+        Fp12 {
+            c0: self.c0 + rhs.c0,
+            c1: self.c1 + rhs.c1,
+        }
+    }
+}
+
+impl Sub for Fp12 {
+    type Output = Fp12;
+    fn sub(self, rhs: Fp12) -> Fp12 {
+        Fp12 {
+            c0: self.c0 - rhs.c0,
+            c1: self.c1 - rhs.c1,
+        }
+    }
+}
+
+//? Karatsuba over Fp6: three Fp6 multiplications
+impl Mul for Fp12 {
+    type Output = Fp12;
+    fn mul(self, rhs: Fp12) -> Fp12 {
+        let v0 = self.c0.clone() * rhs.c0.clone();
+        let v1 = self.c1.clone() * rhs.c1.clone();
+        let c1 = (self.c0 + self.c1) * (rhs.c0 + rhs.c1) - v0.clone() - v1.clone();
+        Fp12 {
+            c0: v0 + v1.mul_by_nonresidue(),
+            c1,
+        }
+    }
+}
+
+impl Neg for Fp12 {
+    type Output = Fp12;
+    fn neg(self) -> Fp12 {
+        Fp12 {
+            c0: -self.c0,
+            c1: -self.c1,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fr::Fr;
+    use crate::g1::G1;
+    use crate::g2::G2;
+    use crate::pairing::pairing;
+    use num_bigint::ToBigUint;
+    use rand::Rng;
+
+    fn random_fp12<R: Rng>(rng: &mut R) -> Fp12 {
+        let mut fp2 = || {
+            Fp2::new(
+                -Fp::new(rng.gen_range(0..u64::MAX).to_biguint().unwrap()),
+                -Fp::new(rng.gen_range(0..u64::MAX).to_biguint().unwrap()),
+            )
+        };
+        Fp12::new(Fp6::new(fp2(), fp2(), fp2()), Fp6::new(fp2(), fp2(), fp2()))
+    }
+
+    #[test]
+    fn test_w_squared_is_v() {
+        let w = Fp12::new(Fp6::zero(), Fp6::one());
+        let v = Fp6::new(Fp2::zero(), Fp2::one(), Fp2::zero());
+        assert_eq!(w.clone() * w, Fp12::new(v, Fp6::zero()));
+    }
+
+    #[test]
+    fn test_inverse_and_distributive() {
+        let mut rng = rand::thread_rng();
+        let a = random_fp12(&mut rng);
+        let b = random_fp12(&mut rng);
+        let c = random_fp12(&mut rng);
+        assert_eq!(a.clone() * a.inv(), Fp12::one());
+        assert_eq!(
+            (a.clone() + b.clone()) * c.clone(),
+            a.clone() * c.clone() + b.clone() * c
+        );
+        assert_eq!(a.clone() * b.clone(), b * a);
+    }
+
+    #[test]
+    fn test_frobenius_matches_pow() {
+        let mut rng = rand::thread_rng();
+        let a = random_fp12(&mut rng);
+        let p = Fp::modulus();
+        assert_eq!(a.frobenius_map(1), a.pow(p));
+        assert_eq!(a.frobenius_map(2), a.pow(&(p * p)));
+        assert_eq!(a.frobenius_map(6), a.conjugate());
+        assert_eq!(a.frobenius_map(12), a);
+    }
+
+    #[test]
+    fn test_mul_by_034() {
+        let mut rng = rand::thread_rng();
+        let a = random_fp12(&mut rng);
+        let b = random_fp12(&mut rng);
+        let (c0, c3, c4) = (b.c0.c0, b.c1.c0, b.c1.c1);
+        let sparse = Fp12::new(
+            Fp6::new(c0.clone(), Fp2::zero(), Fp2::zero()),
+            Fp6::new(c3.clone(), c4.clone(), Fp2::zero()),
+        );
+        assert_eq!(a.mul_by_034(&c0, &c3, &c4), a * sparse);
+    }
+
+    #[test]
+    fn test_unitary_inverse() {
+        let gt = pairing(
+            &G1::generator().mul(&Fr::from_u128(6)),
+            &G2::generator().mul(&Fr::from_u128(7)),
+        );
+        assert_eq!(gt.clone() * gt.unitary_inverse(), Fp12::one());
+        assert_eq!(gt.unitary_inverse(), gt.inv());
+    }
+}
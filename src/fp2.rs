@@ -0,0 +1,276 @@
+use crate::fp::Fp;
+use num_bigint::BigUint;
+use std::ops::{Add, Mul, Neg, Sub};
+
+//? c0 + c1*u with u^2 = -1
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Fp2 {
+    pub c0: Fp,
+    pub c1: Fp,
+}
+
+impl Fp2 {
+    pub fn new(c0: Fp, c1: Fp) -> Self {
+        Fp2 { c0, c1 }
+    }
+
+    pub fn zero() -> Self {
+        Fp2 {
+            c0: Fp::zero(),
+            c1: Fp::zero(),
+        }
+    }
+
+    pub fn one() -> Self {
+        Fp2 {
+            c0: Fp::one(),
+            c1: Fp::zero(),
+        }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        *self == Fp2::zero()
+    }
+
+    pub fn conjugate(&self) -> Self {
+        Fp2 {
+            c0: self.c0.clone(),
+            c1: -self.c1.clone(),
+        }
+    }
+
+    //? N(a) = a * conj(a) = c0^2 + c1^2
+    pub fn norm(&self) -> Fp {
+        self.c0.clone() * self.c0.clone() + self.c1.clone() * self.c1.clone()
+    }
+
+    //? T(a) = a + conj(a) = 2*c0
+    pub fn trace(&self) -> Fp {
+        self.c0.double()
+    }
+
+    //? Multiplication by the Fp6 non-residue xi = 9 + u
+    pub fn mul_by_nonresidue(&self) -> Self {
+        let nine = Fp::new(9u32.into());
+        Fp2 {
+            c0: nine.clone() * self.c0.clone() - self.c1.clone(),
+            c1: self.c0.clone() + nine * self.c1.clone(),
+        }
+    }
+
+    //? (c0*s, c1*s): two Fp products instead of the four of a full Fp2 mul
+    pub fn mul_by_fp(&self, s: &Fp) -> Self {
+        Fp2 {
+            c0: &self.c0 * s,
+            c1: &self.c1 * s,
+        }
+    }
+
+    //? (c0 - c1*u) / (c0^2 + c1^2)
+    pub fn inv(&self) -> Self {
+        self.conjugate().mul_by_fp(&self.norm().inv())
+    }
+
+    //? Montgomery's trick as in Fp::batch_inverse; zeros come back as zero
+    pub fn batch_inverse(values: &[Fp2]) -> Vec<Fp2> {
+        let mut prefix = Vec::with_capacity(values.len());
+        let mut acc = Fp2::one();
+        for v in values {
+            prefix.push(acc.clone());
+            if !v.is_zero() {
+                acc = acc * v.clone();
+            }
+        }
+        let mut inv = acc.inv();
+        let mut out = vec![Fp2::zero(); values.len()];
+        for i in (0..values.len()).rev() {
+            if values[i].is_zero() {
+                continue;
+            }
+            out[i] = inv.clone() * prefix[i].clone();
+            inv = inv * values[i].clone();
+        }
+        out
+    }
+
+    pub fn pow(&self, exp: &BigUint) -> Self {
+        let mut res = Fp2::one();
+        for i in (0..exp.bits()).rev() {
+            res = res.clone() * res;
+            if exp.bit(i) {
+                res = res * self.clone();
+            }
+        }
+        res
+    }
+}
+
+// Operator overloading
+impl Add for Fp2 {
+    type Output = Fp2;
+    fn add(self, rhs: Fp2) -> Fp2 {
+        Fp2 {
+            c0: self.c0 + rhs.c0,
+            c1: self.c1 + rhs.c1,
+        }
+    }
+}
+
+impl Sub for Fp2 {
+    type Output = Fp2;
+    fn sub(self, rhs: Fp2) -> Fp2 {
+        Fp2 {
+            c0: self.c0 - rhs.c0,
+            c1: self.c1 - rhs.c1,
+        }
+    }
+}
+
+//? Karatsuba: three Fp multiplications, with u^2 = -1 folded into c0
+impl Mul for Fp2 {
+    type Output = Fp2;
+    fn mul(self, rhs: Fp2) -> Fp2 {
+        let v0 = self.c0.clone() * rhs.c0.clone();
+        let v1 = self.c1.clone() * rhs.c1.clone();
+        let c1 = (self.c0 + self.c1) * (rhs.c0 + rhs.c1) - v0.clone() - v1.clone();
+        Fp2 { c0: v0 - v1, c1 }
+    }
+}
+
+impl Neg for Fp2 {
+    type Output = Fp2;
+    fn neg(self) -> Fp2 {
+        Fp2 {
+            c0: -self.c0,
+            c1: -self.c1,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_bigint::ToBigUint;
+    use num_traits::One;
+    use rand::Rng;
+
+    fn random_fp2<R: Rng>(rng: &mut R) -> Fp2 {
+        Fp2::new(
+            -Fp::new(rng.gen_range(0..u64::MAX).to_biguint().unwrap()),
+            -Fp::new(rng.gen_range(0..u64::MAX).to_biguint().unwrap()),
+        )
+    }
+
+    #[test]
+    fn test_u_squared_is_minus_one() {
+        let u = Fp2::new(Fp::zero(), Fp::one());
+        assert_eq!(u.clone() * u, -Fp2::one());
+    }
+
+    #[test]
+    fn test_mul_by_nonresidue() {
+        let mut rng = rand::thread_rng();
+        let xi = Fp2::new(Fp::new(9u32.into()), Fp::one());
+        let a = random_fp2(&mut rng);
+        assert_eq!(a.mul_by_nonresidue(), a * xi);
+    }
+
+    #[test]
+    fn test_mul_by_fp() {
+        let mut rng = rand::thread_rng();
+        let a = random_fp2(&mut rng);
+        let s = -Fp::new(rng.gen_range(0..u64::MAX).to_biguint().unwrap());
+        assert_eq!(a.mul_by_fp(&s), a.clone() * Fp2::new(s, Fp::zero()));
+        assert!(a.mul_by_fp(&Fp::zero()).is_zero());
+    }
+
+    #[test]
+    fn test_norm_and_trace() {
+        let mut rng = rand::thread_rng();
+        let a = random_fp2(&mut rng);
+        assert_eq!(
+            a.norm(),
+            a.c0.clone() * a.c0.clone() + a.c1.clone() * a.c1.clone()
+        );
+        assert_eq!(a.trace(), a.c0.clone() + a.c0.clone());
+        assert_eq!(Fp2::new(a.norm(), Fp::zero()), a.clone() * a.conjugate());
+        assert_eq!(Fp2::new(a.trace(), Fp::zero()), a.clone() + a.conjugate());
+    }
+
+    #[test]
+    fn test_inverse() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..10 {
+            let a = random_fp2(&mut rng);
+            assert_eq!(a.clone() * a.inv(), Fp2::one());
+        }
+        let real = Fp2::new(Fp::new(7u32.into()), Fp::zero());
+        assert_eq!(real.inv().c0, Fp::new(7u32.into()).inv());
+    }
+
+    #[test]
+    fn test_inverse_consistency() {
+        let mut rng = rand::thread_rng();
+        let p = Fp::modulus();
+        let fermat_exp = p * p - BigUint::from(2u32);
+        for _ in 0..3 {
+            let a = random_fp2(&mut rng);
+            let via_norm = a.conjugate() * Fp2::new(a.norm().inv(), Fp::zero());
+            assert_eq!(a.inv(), via_norm);
+            assert_eq!(a.inv(), a.pow(&fermat_exp));
+        }
+    }
+
+    #[test]
+    fn test_batch_inverse() {
+        let mut rng = rand::thread_rng();
+        let mut values: Vec<Fp2> = (0..6).map(|_| random_fp2(&mut rng)).collect();
+        values.insert(2, Fp2::zero());
+        let inverses = Fp2::batch_inverse(&values);
+        for (v, inv) in values.iter().zip(&inverses) {
+            if v.is_zero() {
+                assert!(inv.is_zero());
+            } else {
+                assert_eq!(*inv, v.inv());
+            }
+        }
+        assert!(Fp2::batch_inverse(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_distributive() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..10 {
+            let a = random_fp2(&mut rng);
+            let b = random_fp2(&mut rng);
+            let c = random_fp2(&mut rng);
+            assert_eq!(
+                (a.clone() + b.clone()) * c.clone(),
+                a.clone() * c.clone() + b.clone() * c.clone()
+            );
+            assert_eq!(a.clone() * b.clone(), b.clone() * a.clone());
+            assert_eq!(a.clone() - a.clone(), Fp2::zero());
+        }
+    }
+
+    #[test]
+    fn test_conjugate_and_pow() {
+        let mut rng = rand::thread_rng();
+        let a = random_fp2(&mut rng);
+        let norm = a.clone() * a.conjugate();
+        assert_eq!(norm.c1, Fp::zero());
+
+        assert_eq!(a.pow(&BigUint::from(0u32)), Fp2::one());
+        assert_eq!(
+            a.pow(&BigUint::from(3u32)),
+            a.clone() * a.clone() * a.clone()
+        );
+
+        // The multiplicative group of Fp2 has order p^2 - 1
+        let p = Fp::modulus();
+        let order = p * p - BigUint::one();
+        assert_eq!(a.pow(&order), Fp2::one());
+        // Frobenius: a^p is the conjugate
+        assert_eq!(a.pow(p), a.conjugate());
+    }
+}
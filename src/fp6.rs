@@ -0,0 +1,197 @@
+use crate::fp2::Fp2;
+use std::ops::{Add, Mul, Neg, Sub};
+
+//? c0 + c1*v + c2*v^2 with v^3 = xi = 9 + u
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Fp6 {
+    pub c0: Fp2,
+    pub c1: Fp2,
+    pub c2: Fp2,
+}
+
+impl Fp6 {
+    pub fn new(c0: Fp2, c1: Fp2, c2: Fp2) -> Self {
+        Fp6 { c0, c1, c2 }
+    }
+
+    pub fn zero() -> Self {
+        Fp6 {
+            c0: Fp2::zero(),
+            c1: Fp2::zero(),
+            c2: Fp2::zero(),
+        }
+    }
+
+    pub fn one() -> Self {
+        Fp6 {
+            c0: Fp2::one(),
+            c1: Fp2::zero(),
+            c2: Fp2::zero(),
+        }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        *self == Fp6::zero()
+    }
+
+    //? Multiplication by v, the Fp12 non-residue
+    pub fn mul_by_nonresidue(&self) -> Self {
+        Fp6 {
+            c0: self.c2.mul_by_nonresidue(),
+            c1: self.c0.clone(),
+            c2: self.c1.clone(),
+        }
+    }
+
+    //? self * (c0 + c1*v), five Fp2 multiplications instead of six
+    pub fn mul_by_01(&self, c0: &Fp2, c1: &Fp2) -> Self {
+        let a_a = self.c0.clone() * c0.clone();
+        let b_b = self.c1.clone() * c1.clone();
+        let t1 = ((self.c1.clone() + self.c2.clone()) * c1.clone() - b_b.clone())
+            .mul_by_nonresidue()
+            + a_a.clone();
+        let t2 = (self.c0.clone() + self.c1.clone()) * (c0.clone() + c1.clone())
+            - a_a.clone()
+            - b_b.clone();
+        let t3 = (self.c0.clone() + self.c2.clone()) * c0.clone() - a_a + b_b;
+        Fp6 {
+            c0: t1,
+            c1: t2,
+            c2: t3,
+        }
+    }
+
+    pub fn inv(&self) -> Self {
+        let t0 = self.c0.clone() * self.c0.clone()
+            - (self.c1.clone() * self.c2.clone()).mul_by_nonresidue();
+        let t1 = (self.c2.clone() * self.c2.clone()).mul_by_nonresidue()
+            - self.c0.clone() * self.c1.clone();
+        let t2 = self.c1.clone() * self.c1.clone() - self.c0.clone() * self.c2.clone();
+        let norm = self.c0.clone() * t0.clone()
+            + (self.c2.clone() * t1.clone() + self.c1.clone() * t2.clone()).mul_by_nonresidue();
+        let norm_inv = norm.inv();
+        Fp6 {
+            c0: t0 * norm_inv.clone(),
+            c1: t1 * norm_inv.clone(),
+            c2: t2 * norm_inv,
+        }
+    }
+}
+
+// Operator overloading
+impl Add for Fp6 {
+    type Output = Fp6;
+    fn add(self, rhs: Fp6) -> Fp6 {
+        Fp6 {
+            c0: self.c0 + rhs.c0,
+            c1: self.c1 + rhs.c1,
+            c2: self.c2 + rhs.c2,
+        }
+    }
+}
+
+impl Sub for Fp6 {
+    type Output = Fp6;
+    fn sub(self, rhs: Fp6) -> Fp6 {
+        Fp6 {
+            c0: self.c0 - rhs.c0,
+            c1: self.c1 - rhs.c1,
+            c2: self.c2 - rhs.c2,
+        }
+    }
+}
+
+//? Karatsuba over Fp2: six Fp2 multiplications
+impl Mul for Fp6 {
+    type Output = Fp6;
+    fn mul(self, rhs: Fp6) -> Fp6 {
+        let v0 = self.c0.clone() * rhs.c0.clone();
+        let v1 = self.c1.clone() * rhs.c1.clone();
+        let v2 = self.c2.clone() * rhs.c2.clone();
+        let c0 = ((self.c1.clone() + self.c2.clone()) * (rhs.c1.clone() + rhs.c2.clone())
+            - v1.clone()
+            - v2.clone())
+        .mul_by_nonresidue()
+            + v0.clone();
+        let c1 = (self.c0.clone() + self.c1.clone()) * (rhs.c0.clone() + rhs.c1.clone())
+            - v0.clone()
+            - v1.clone()
+            + v2.mul_by_nonresidue();
+        let c2 = (self.c0 + self.c2) * (rhs.c0 + rhs.c2) - v0 - v2 + v1;
+        Fp6 { c0, c1, c2 }
+    }
+}
+
+impl Neg for Fp6 {
+    type Output = Fp6;
+    fn neg(self) -> Fp6 {
+        Fp6 {
+            c0: -self.c0,
+            c1: -self.c1,
+            c2: -self.c2,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fp::Fp;
+    use num_bigint::ToBigUint;
+    use rand::Rng;
+
+    fn random_fp6<R: Rng>(rng: &mut R) -> Fp6 {
+        let mut fp = || -Fp::new(rng.gen_range(0..u64::MAX).to_biguint().unwrap());
+        Fp6::new(
+            Fp2::new(fp(), fp()),
+            Fp2::new(fp(), fp()),
+            Fp2::new(fp(), fp()),
+        )
+    }
+
+    #[test]
+    fn test_v_cubed_is_xi() {
+        let v = Fp6::new(Fp2::zero(), Fp2::one(), Fp2::zero());
+        let xi = Fp2::new(Fp::new(9u32.into()), Fp::one());
+        assert_eq!(
+            v.clone() * v.clone() * v,
+            Fp6::new(xi, Fp2::zero(), Fp2::zero())
+        );
+    }
+
+    #[test]
+    fn test_inverse() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..5 {
+            let a = random_fp6(&mut rng);
+            assert_eq!(a.clone() * a.inv(), Fp6::one());
+        }
+    }
+
+    #[test]
+    fn test_distributive_and_nonresidue() {
+        let mut rng = rand::thread_rng();
+        let a = random_fp6(&mut rng);
+        let b = random_fp6(&mut rng);
+        let c = random_fp6(&mut rng);
+        assert_eq!(
+            (a.clone() + b.clone()) * c.clone(),
+            a.clone() * c.clone() + b.clone() * c.clone()
+        );
+        assert_eq!(a.clone() * b.clone(), b * a.clone());
+
+        let v = Fp6::new(Fp2::zero(), Fp2::one(), Fp2::zero());
+        assert_eq!(a.mul_by_nonresidue(), a * v);
+    }
+
+    #[test]
+    fn test_sparse_mul() {
+        let mut rng = rand::thread_rng();
+        let a = random_fp6(&mut rng);
+        let b = random_fp6(&mut rng);
+        assert_eq!(
+            a.mul_by_01(&b.c0, &b.c1),
+            a * Fp6::new(b.c0, b.c1, Fp2::zero())
+        );
+    }
+}
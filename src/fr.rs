@@ -0,0 +1,337 @@
+use crate::error::Error;
+use lazy_static::lazy_static;
+use num_bigint::{BigInt, BigUint, Sign};
+use num_traits::{One, Zero};
+use std::ops::{Add, Mul, Neg, Sub};
+
+lazy_static! {
+    //? Order r of the BN254 G1/G2 groups
+    static ref R: BigUint = BigUint::parse_bytes(
+        b"21888242871839275222246405745257275088548364400416034343698204186575808495617",
+        10
+    )
+    .unwrap();
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Fr {
+    pub n: BigUint,
+}
+
+impl Fr {
+    pub fn new(n: BigUint) -> Self {
+        let n = n % &*R;
+        Fr { n }
+    }
+
+    pub fn modulus() -> &'static BigUint {
+        &R
+    }
+
+    pub fn zero() -> Self {
+        Fr { n: BigUint::zero() }
+    }
+
+    pub fn one() -> Self {
+        Fr { n: BigUint::one() }
+    }
+
+    pub fn from_u128(n: u128) -> Self {
+        Fr::new(BigUint::from(n))
+    }
+
+    //? Uniform up to a 2^-256 bias: 512 random bits reduced mod r
+    pub fn random<G: rand::Rng>(rng: &mut G) -> Self {
+        let mut bytes = [0u8; 64];
+        rng.fill_bytes(&mut bytes);
+        Fr::new(BigUint::from_bytes_be(&bytes))
+    }
+
+    pub fn inv(&self) -> Self {
+        let mut a = BigInt::from(self.n.clone());
+        let mut m = BigInt::from(R.clone());
+        let mut x0 = BigInt::zero();
+        let mut x1 = BigInt::one();
+
+        if a.is_zero() {
+            panic!("Inverse does not exist for zero");
+        }
+
+        while a != BigInt::one() {
+            let q = &a / &m;
+            let mut t = m.clone();
+            m = &a % &m;
+            a = t;
+            t = x0.clone();
+            x0 = &x1 - &q * &x0;
+            x1 = t;
+        }
+
+        if x1.sign() == Sign::Minus {
+            x1 += BigInt::from(R.clone());
+        }
+
+        Fr::new(x1.to_biguint().unwrap())
+    }
+
+    //? Montgomery's trick, as Fp::batch_inverse: zeros come back as zero
+    pub fn batch_inverse(values: &[Fr]) -> Vec<Fr> {
+        let mut prefix = Vec::with_capacity(values.len());
+        let mut acc = Fr::one();
+        for v in values {
+            prefix.push(acc.clone());
+            if !v.n.is_zero() {
+                acc = acc * v.clone();
+            }
+        }
+        let mut inv = acc.inv();
+        let mut out = vec![Fr::zero(); values.len()];
+        for i in (0..values.len()).rev() {
+            if values[i].n.is_zero() {
+                continue;
+            }
+            out[i] = inv.clone() * prefix[i].clone();
+            inv = inv * values[i].clone();
+        }
+        out
+    }
+
+    pub fn pow(&self, exp: &BigUint) -> Self {
+        Fr::new(self.n.modpow(exp, &R))
+    }
+
+    pub fn to_bytes_be(&self) -> [u8; 32] {
+        let raw = self.n.to_bytes_be();
+        let mut out = [0u8; 32];
+        out[32 - raw.len()..].copy_from_slice(&raw);
+        out
+    }
+
+    //? Rejects encodings of values >= r rather than reducing them
+    pub fn from_bytes_be(bytes: &[u8; 32]) -> Result<Self, Error> {
+        let n = BigUint::from_bytes_be(bytes);
+        if n >= *R {
+            return Err(Error::NonCanonical);
+        }
+        Ok(Fr { n })
+    }
+}
+
+//? r - 1 = 2^28 * odd, so power-of-two domains go up to 2^28
+const TWO_ADICITY: u32 = 28;
+
+//? 5 generates Fr*, so 5^((r-1)/size) is a primitive size-th root of unity
+pub fn fr_domain(size: usize) -> Result<Vec<Fr>, Error> {
+    if !size.is_power_of_two() || size.trailing_zeros() > TWO_ADICITY {
+        return Err(Error::InvalidDomainSize);
+    }
+    let omega = Fr::from_u128(5).pow(&((&*R - BigUint::one()) / size));
+    let mut domain = Vec::with_capacity(size);
+    let mut acc = Fr::one();
+    for _ in 0..size {
+        domain.push(acc.clone());
+        acc = acc * omega.clone();
+    }
+    Ok(domain)
+}
+
+//? Quotient of f(x) by (x - z) via synthetic division, coefficients lowest
+//? degree first. Assumes f(z) = 0, as for f(x) - f(z) in a KZG opening; a
+//? nonzero remainder is silently dropped.
+pub fn divide_by_linear(coeffs: &[Fr], z: &Fr) -> Vec<Fr> {
+    if coeffs.len() < 2 {
+        return Vec::new();
+    }
+    let mut quotient = vec![Fr::zero(); coeffs.len() - 1];
+    let mut carry = Fr::zero();
+    for i in (1..coeffs.len()).rev() {
+        carry = coeffs[i].clone() + carry * z.clone();
+        quotient[i - 1] = carry.clone();
+    }
+    quotient
+}
+
+//? Bit length of the group order; fixed-length scalar loops run this many steps
+pub const fn max_scalar_bits() -> usize {
+    254
+}
+
+//? Significant bits of a scalar; max_scalar_bits() minus this is its leading zero count
+pub fn scalar_bit_length(s: &BigUint) -> usize {
+    s.bits() as usize
+}
+
+// Operator overloading
+impl Add for Fr {
+    type Output = Fr;
+    fn add(self, rhs: Fr) -> Fr {
+        Fr::new(self.n + rhs.n)
+    }
+}
+
+impl Sub for Fr {
+    type Output = Fr;
+    fn sub(self, rhs: Fr) -> Fr {
+        let res = if self.n >= rhs.n {
+            &self.n - &rhs.n
+        } else {
+            &self.n + &*R - &rhs.n
+        };
+        Fr::new(res)
+    }
+}
+
+impl Mul for Fr {
+    type Output = Fr;
+    fn mul(self, rhs: Fr) -> Fr {
+        Fr::new(self.n * rhs.n)
+    }
+}
+
+impl Neg for Fr {
+    type Output = Fr;
+    fn neg(self) -> Fr {
+        if self.n.is_zero() {
+            Fr::zero()
+        } else {
+            Fr::new(&*R - self.n)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fp::Fp;
+    use rand::Rng;
+
+    #[test]
+    fn test_order_differs_from_fp() {
+        assert_ne!(Fr::modulus(), Fp::modulus());
+        assert!(Fr::modulus() < Fp::modulus());
+        assert_eq!(Fr::modulus().bits(), 254);
+    }
+
+    #[test]
+    fn test_inverse() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..10 {
+            let a = -Fr::from_u128(rng.gen_range(1..u128::MAX));
+            let one = a.clone() * a.inv();
+            assert_eq!(one, Fr::one());
+        }
+    }
+
+    #[test]
+    fn test_batch_inverse() {
+        let mut rng = rand::thread_rng();
+        let mut values: Vec<Fr> = (0..16).map(|_| Fr::random(&mut rng)).collect();
+        values[5] = Fr::zero();
+        let inverses = Fr::batch_inverse(&values);
+        for (v, inv) in values.iter().zip(&inverses) {
+            if v.n.is_zero() {
+                assert_eq!(*inv, Fr::zero());
+            } else {
+                assert_eq!(*inv, v.inv());
+            }
+        }
+        assert!(Fr::batch_inverse(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_fermat_little_theorem() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..10 {
+            let a = Fr::from_u128(rng.gen_range(1..1000));
+            let exp = &*R - BigUint::one();
+            assert_eq!(a.pow(&exp), Fr::one());
+        }
+    }
+
+    #[test]
+    fn test_basic_add_sub_mul_neg() {
+        let a = Fr::from_u128(10);
+        let b = Fr::from_u128(15);
+
+        assert_eq!(a.clone() + b.clone(), Fr::from_u128(25));
+        assert_eq!(b.clone() - a.clone(), Fr::from_u128(5));
+        assert_eq!(a.clone() - b.clone(), -Fr::from_u128(5));
+        assert_eq!(a.clone() * b.clone(), Fr::from_u128(150));
+        assert_eq!(-a.clone(), Fr::new(&*R - BigUint::from(10u32)));
+        assert_eq!(Fr::new(R.clone()), Fr::zero());
+    }
+
+    #[test]
+    fn test_fr_domain() {
+        for size in [1usize, 2, 8, 64] {
+            let domain = fr_domain(size).unwrap();
+            assert_eq!(domain.len(), size);
+            assert_eq!(domain[0], Fr::one());
+            for (i, a) in domain.iter().enumerate() {
+                assert!(domain[i + 1..].iter().all(|b| b != a));
+            }
+            if size > 1 {
+                // omega^size = 1 closes the cycle
+                let omega = domain[1].clone();
+                assert_eq!(domain[size - 1].clone() * omega, Fr::one());
+            }
+        }
+        assert_eq!(
+            (&*R - BigUint::one()).trailing_zeros(),
+            Some(TWO_ADICITY as u64)
+        );
+        assert_eq!(fr_domain(0), Err(Error::InvalidDomainSize));
+        assert_eq!(fr_domain(12), Err(Error::InvalidDomainSize));
+        assert_eq!(fr_domain(1 << 29), Err(Error::InvalidDomainSize));
+    }
+
+    #[test]
+    fn test_divide_by_linear() {
+        let mut rng = rand::thread_rng();
+        let z = Fr::random(&mut rng);
+        let mut f: Vec<Fr> = (0..6).map(|_| Fr::random(&mut rng)).collect();
+        // Subtract f(z) from the constant term so z is a root
+        let f_z = f
+            .iter()
+            .rev()
+            .fold(Fr::zero(), |acc, c| acc * z.clone() + c.clone());
+        f[0] = f[0].clone() - f_z;
+
+        let q = divide_by_linear(&f, &z);
+        assert_eq!(q.len(), f.len() - 1);
+        // q(x) * (x - z) = sum q_i x^(i+1) - z q_i x^i
+        let mut product = vec![Fr::zero(); f.len()];
+        for (i, c) in q.iter().enumerate() {
+            product[i + 1] = product[i + 1].clone() + c.clone();
+            product[i] = product[i].clone() - c.clone() * z.clone();
+        }
+        assert_eq!(product, f);
+
+        assert!(divide_by_linear(&[Fr::one()], &z).is_empty());
+        assert!(divide_by_linear(&[], &z).is_empty());
+    }
+
+    #[test]
+    fn test_scalar_bit_length() {
+        assert_eq!(scalar_bit_length(&BigUint::zero()), 0);
+        assert_eq!(scalar_bit_length(&BigUint::one()), 1);
+        assert_eq!(scalar_bit_length(&BigUint::from(255u32)), 8);
+        assert_eq!(scalar_bit_length(&BigUint::from(256u32)), 9);
+        assert_eq!(scalar_bit_length(&(-Fr::one()).n), max_scalar_bits());
+        assert_eq!(max_scalar_bits(), R.bits() as usize);
+    }
+
+    #[test]
+    fn test_bytes_round_trip() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..10 {
+            let a = -Fr::from_u128(rng.gen_range(0..u128::MAX));
+            assert_eq!(Fr::from_bytes_be(&a.to_bytes_be()).unwrap(), a);
+        }
+        assert_eq!(Fr::from_u128(1).to_bytes_be()[31], 1);
+
+        let mut r_bytes = [0u8; 32];
+        r_bytes.copy_from_slice(&R.to_bytes_be());
+        assert_eq!(Fr::from_bytes_be(&r_bytes), Err(Error::NonCanonical));
+    }
+}
@@ -1,6 +1,78 @@
+use crate::base64;
+use crate::error::Error;
 use crate::fp::Fp;
+use crate::fr::{Fr, max_scalar_bits, scalar_bit_length};
+use crate::params::{G1_B, G1_B3};
+use lazy_static::lazy_static;
+use num_bigint::BigUint;
 use num_traits::Zero;
-#[derive(Clone, Debug, PartialEq, Eq)]
+use std::fmt;
+use std::ops::Mul;
+
+lazy_static! {
+    static ref GENERATOR: G1 = G1 {
+        x: Fp::new(1u32.into()),
+        y: Fp::new(2u32.into()),
+        z: Fp::one(),
+    };
+
+    //? Shortest addition chains for 0..=255, as the step list of star_chain
+    static ref SMALL_CHAINS: Vec<Vec<usize>> = (0..=255u16).map(star_chain).collect();
+
+    //? n * G for n in 0..=SMALL_GENERATOR_MAX, built on first use
+    static ref SMALL_GENERATOR_MULTIPLES: Vec<G1> = {
+        let mut table = vec![G1::infinity(), GENERATOR.clone()];
+        for n in 2..=SMALL_GENERATOR_MAX as usize {
+            table.push(table[n - 1].add(&GENERATOR));
+        }
+        table
+    };
+}
+
+const SMALL_GENERATOR_MAX: u8 = 16;
+
+//? Shortest star chain 1 = a_0 < ... < a_k = n with a_i = a_{i-1} + a_{j_i},
+//? returned as [j_1, ..., j_k]. Star chains are optimal for every n < 12509.
+fn star_chain(n: u16) -> Vec<usize> {
+    fn search(values: &mut Vec<u16>, steps: &mut Vec<usize>, n: u16, limit: usize) -> bool {
+        let last = *values.last().unwrap();
+        if last == n {
+            return true;
+        }
+        let left = limit - steps.len();
+        if left == 0 || (last as u32) << left < n as u32 {
+            return false;
+        }
+        for j in (0..values.len()).rev() {
+            let next = last + values[j];
+            if next > n {
+                continue;
+            }
+            values.push(next);
+            steps.push(j);
+            if search(values, steps, n, limit) {
+                return true;
+            }
+            values.pop();
+            steps.pop();
+        }
+        false
+    }
+
+    if n <= 1 {
+        return Vec::new();
+    }
+    let mut limit = 1;
+    loop {
+        let (mut values, mut steps) = (vec![1], Vec::new());
+        if search(&mut values, &mut steps, n, limit) {
+            return steps;
+        }
+        limit += 1;
+    }
+}
+
+#[derive(Clone, PartialEq, Eq)]
 pub struct G1 {
     pub x: Fp,
     pub y: Fp,
@@ -16,20 +88,78 @@ impl G1 {
         }
     }
 
+    pub fn generator() -> Self {
+        GENERATOR.clone()
+    }
+
     pub fn is_infinity(&self) -> bool {
         self.z.n.is_zero()
     }
 
+    //? Maps any z = 0 representation to the canonical (0, 1, 0)
+    fn normalize_infinity(self) -> Self {
+        if self.is_infinity() {
+            Self::infinity()
+        } else {
+            self
+        }
+    }
+
+    //? Equality of the underlying points, independent of Jacobian scaling
+    pub fn eq_projective(&self, other: &Self) -> bool {
+        if self.is_infinity() || other.is_infinity() {
+            return self.is_infinity() && other.is_infinity();
+        }
+        let z1z1 = self.z.clone() * self.z.clone();
+        let z2z2 = other.z.clone() * other.z.clone();
+        self.x.clone() * z2z2.clone() == other.x.clone() * z1z1.clone()
+            && self.y.clone() * z2z2 * other.z.clone() == other.y.clone() * z1z1 * self.z.clone()
+    }
+
+    //? self == (x, y) via x*Z^2 and y*Z^3, without inverting Z
+    pub fn equals_affine(&self, x: &Fp, y: &Fp) -> bool {
+        if self.is_infinity() {
+            return false;
+        }
+        let zz = &self.z * &self.z;
+        self.x == x * &zz && self.y == &(y * &zz) * &self.z
+    }
+
+    pub fn is_generator(&self) -> bool {
+        self.eq_projective(&GENERATOR)
+    }
+
     pub fn to_affine(&self) -> (Fp, Fp) {
+        let (x_aff, y_aff, _) = self.to_affine_with_zinv();
+        (x_aff, y_aff)
+    }
+
+    //? Affine coordinates plus the z-inverse used to get them (all zero at infinity)
+    pub fn to_affine_with_zinv(&self) -> (Fp, Fp, Fp) {
         if self.is_infinity() {
-            return (Fp::zero(), Fp::zero());
+            return (Fp::zero(), Fp::zero(), Fp::zero());
         }
         let z_inv = self.z.inv();
         let z2 = z_inv.clone() * z_inv.clone();
-        let z3 = z2.clone() * z_inv;
+        let z3 = z2.clone() * z_inv.clone();
         let x_aff = self.x.clone() * z2;
         let y_aff = self.y.clone() * z3;
-        (x_aff, y_aff)
+        (x_aff, y_aff, z_inv)
+    }
+
+    //? Affine coordinates for many points with a single field inversion;
+    //? infinity maps to (0, 0) as in to_affine
+    pub fn batch_to_affine(points: &[G1]) -> Vec<(Fp, Fp)> {
+        let zs: Vec<Fp> = points.iter().map(|p| p.z.clone()).collect();
+        points
+            .iter()
+            .zip(Fp::batch_inverse(&zs))
+            .map(|(p, z_inv)| {
+                let z2 = z_inv.clone() * z_inv.clone();
+                let z3 = z2.clone() * z_inv;
+                (p.x.clone() * z2, p.y.clone() * z3)
+            })
+            .collect()
     }
 
     pub fn is_on_curve(&self) -> bool {
@@ -37,50 +167,85 @@ impl G1 {
             return true;
         }
         let (x, y) = self.to_affine();
-        y.clone() * y.clone() == x.clone() * x.clone() * x.clone() + Fp::new(3u32.into())
+        affine_on_curve(&x, &y)
+    }
+
+    pub fn neg(&self) -> Self {
+        Self {
+            x: self.x.clone(),
+            y: -self.y.clone(),
+            z: self.z.clone(),
+        }
+    }
+
+    //? Negates table[i] in place for each listed i, e.g. to flip wNAF table
+    //? entries between calls. Infinity is left as is; a repeated index negates
+    //? twice, and an out-of-range one panics.
+    pub fn negate_slice_at(table: &mut [G1], indices: &[usize]) {
+        for &i in indices {
+            let p = &mut table[i];
+            if !p.is_infinity() {
+                p.y = -p.y.clone();
+            }
+        }
     }
 
     //? Doubling in Jacobian coordinates
     pub fn double(&self) -> Self {
         if self.is_infinity() {
-            return self.clone();
+            return Self::infinity();
         }
 
-        let xx = self.x.clone() * self.x.clone();
-        let yy = self.y.clone() * self.y.clone();
-        let yyyy = yy.clone() * yy.clone();
-        let s = ((self.x.clone() + yy.clone()) * (self.x.clone() + yy.clone())
-            - xx.clone()
-            - yyyy.clone())
-            + ((self.x.clone() + yy.clone()) * (self.x.clone() + yy.clone())
-                - xx.clone()
-                - yyyy.clone()); // 2*S
-        let m = xx.clone() + xx.clone() + xx.clone(); // 3*XX
-        let x3 = m.clone() * m.clone() - s.clone() - s.clone();
-        let y3 = m * (s - x3.clone()) - yyyy.clone() - yyyy.clone() - yyyy.clone() - yyyy.clone(); // 8*YYYY
-        let z3 = (self.y.clone() * self.z.clone()) + (self.y.clone() * self.z.clone()); // 2*Y1*Z1
+        let xx = &self.x * &self.x;
+        let yy = &self.y * &self.y;
+        let yyyy = &yy * &yy;
+        let x_yy = &self.x + &yy;
+        let mut t = &x_yy * &x_yy;
+        t -= &xx;
+        t -= &yyyy;
+        let s = t.double(); // 2*S
+        let m = xx.triple(); // 3*XX
+        let mut x3 = &m * &m;
+        x3 -= &s.double();
+        let mut y3 = &m * &(&s - &x3);
+        y3 -= &yyyy.double().double().double(); // 8*YYYY
+        let z3 = (&self.y * &self.z).double(); // 2*Y1*Z1
         Self {
             x: x3,
             y: y3,
             z: z3,
         }
+        .normalize_infinity()
+    }
+
+    pub fn double_n(&self, k: usize) -> Self {
+        let mut res = self.clone();
+        for _ in 0..k {
+            res = res.double();
+        }
+        res
+    }
+
+    //? (2^k + 1) * P as k doublings and one addition
+    pub fn mul_pow2_plus_one(&self, k: usize) -> Self {
+        self.double_n(k).add(self)
     }
 
     //? Addition in Jacobian coordinates
     pub fn add(&self, other: &Self) -> Self {
         if self.is_infinity() {
-            return other.clone();
+            return other.clone().normalize_infinity();
         }
         if other.is_infinity() {
             return self.clone();
         }
 
-        let z1z1 = self.z.clone() * self.z.clone();
-        let z2z2 = other.z.clone() * other.z.clone();
-        let u1 = self.x.clone() * z2z2.clone();
-        let u2 = other.x.clone() * z1z1.clone();
-        let s1 = self.y.clone() * z2z2.clone() * other.z.clone();
-        let s2 = other.y.clone() * z1z1.clone() * self.z.clone();
+        let z1z1 = &self.z * &self.z;
+        let z2z2 = &other.z * &other.z;
+        let u1 = &self.x * &z2z2;
+        let u2 = &other.x * &z1z1;
+        let s1 = &(&self.y * &z2z2) * &other.z;
+        let s2 = &(&other.y * &z1z1) * &self.z;
 
         if u1 == u2 {
             if s1 == s2 {
@@ -90,123 +255,1662 @@ impl G1 {
             }
         }
 
-        let h = u2 - u1.clone();
-        let i = (h.clone() + h.clone()) * (h.clone() + h.clone());
+        let h = &u2 - &u1;
+        let h2 = h.double();
+        let i = &h2 * &h2;
+        let j = &h * &i;
+        let r = (&s2 - &s1).double();
+        let v = &u1 * &i;
+
+        let mut x3 = &r * &r;
+        x3 -= &j;
+        x3 -= &v.double();
+        let mut y3 = &r * &(&v - &x3);
+        y3 -= &(&s1 * &j).double();
+        let z12 = &self.z + &other.z;
+        let mut z3 = &z12 * &z12;
+        z3 -= &z1z1;
+        z3 -= &z2z2;
+        z3 *= &h;
+
+        Self {
+            x: x3,
+            y: y3,
+            z: z3,
+        }
+        .normalize_infinity()
+    }
+
+    //? Mixed addition where `other` is affine (z = 1)
+    fn add_mixed(&self, other: &Self) -> Self {
+        if self.is_infinity() {
+            return other.clone().normalize_infinity();
+        }
+
+        let z1z1 = self.z.clone() * self.z.clone();
+        let u2 = other.x.clone() * z1z1.clone();
+        let s2 = other.y.clone() * self.z.clone() * z1z1.clone();
+
+        if self.x == u2 {
+            if self.y == s2 {
+                return self.double();
+            } else {
+                return Self::infinity();
+            }
+        }
+
+        let h = u2 - self.x.clone();
+        let hh = h.clone() * h.clone();
+        let i = hh.clone() + hh.clone() + hh.clone() + hh.clone(); // 4*HH
         let j = h.clone() * i.clone();
-        let r = (s2.clone() - s1.clone()) + (s2 - s1.clone());
-        let v = u1.clone() * i;
+        let r = (s2.clone() - self.y.clone()) + (s2 - self.y.clone());
+        let v = self.x.clone() * i;
 
         let x3 = r.clone() * r.clone() - j.clone() - v.clone() - v.clone();
-        let y3 = r * (v - x3.clone()) - s1.clone() * j.clone() - s1.clone() * j.clone();
-        let z3 = ((self.z.clone() + other.z.clone()).clone() * (self.z.clone() + other.z.clone())
-            - z1z1
-            - z2z2)
-            * h;
+        let y3 = r * (v - x3.clone()) - self.y.clone() * j.clone() - self.y.clone() * j;
+        let z3 = (self.z.clone() + h.clone()) * (self.z.clone() + h) - z1z1 - hh;
 
         Self {
             x: x3,
             y: y3,
             z: z3,
         }
+        .normalize_infinity()
     }
 
-    //? Scalar multiplication using double-and-add
-    pub fn mul_u128(&self, mut scalar: u128) -> Self {
-        let mut res = Self::infinity();
-        let mut base = self.clone();
+    //? Elementwise sum of two slices, using mixed addition when a side is affine
+    pub fn batch_add(a: &[G1], b: &[G1]) -> Result<Vec<G1>, Error> {
+        if a.len() != b.len() {
+            return Err(Error::LengthMismatch {
+                left: a.len(),
+                right: b.len(),
+            });
+        }
+
+        Ok(a.iter()
+            .zip(b)
+            .map(|(p, q)| {
+                if !q.is_infinity() && q.z == Fp::one() {
+                    p.add_mixed(q)
+                } else if !p.is_infinity() && p.z == Fp::one() {
+                    q.add_mixed(p)
+                } else {
+                    p.add(q)
+                }
+            })
+            .collect())
+    }
+
+    //? Sum of c_i * P_i for small signed c_i; negative terms use -P_i
+    pub fn signed_linear_combo(points: &[G1], coeffs: &[i64]) -> Self {
+        assert_eq!(
+            points.len(),
+            coeffs.len(),
+            "points and coeffs differ in length"
+        );
+        points
+            .iter()
+            .zip(coeffs)
+            .fold(Self::infinity(), |acc, (p, c)| {
+                let term = p.mul_u128(c.unsigned_abs() as u128);
+                if *c < 0 {
+                    acc.add(&term.neg())
+                } else {
+                    acc.add(&term)
+                }
+            })
+    }
+
+    //? Sum of the points with signs[i] = true meaning subtract P_i
+    pub fn alternating_sum(points: &[G1], signs: &[bool]) -> Result<Self, Error> {
+        if points.len() != signs.len() {
+            return Err(Error::LengthMismatch {
+                left: points.len(),
+                right: signs.len(),
+            });
+        }
+
+        Ok(points
+            .iter()
+            .zip(signs)
+            .fold(Self::infinity(), |acc, (p, negate)| {
+                if *negate {
+                    acc.add(&p.neg())
+                } else {
+                    acc.add(p)
+                }
+            }))
+    }
+
+    //? Blinds self as self + r*h for a fresh random r, returning the point and r
+    pub fn rerandomize<R: rand::Rng>(&self, h: &G1, rng: &mut R) -> (G1, Fr) {
+        let r = Fr::random(rng);
+        (self.add(&h.mul(&r)), r)
+    }
 
-        while scalar > 0 {
-            if scalar & 1 == 1 {
-                res = res.add(&base);
+    //? n * P along a precomputed shortest addition chain, for tiny n
+    pub fn small_mul(&self, n: u8) -> Self {
+        if n == 0 {
+            return Self::infinity();
+        }
+        let mut chain = vec![self.clone()];
+        for &j in &SMALL_CHAINS[n as usize] {
+            let last = chain.last().unwrap();
+            let next = if j == chain.len() - 1 {
+                last.double()
+            } else {
+                last.add(&chain[j])
+            };
+            chain.push(next);
+        }
+        chain.pop().unwrap()
+    }
+
+    //? n * G, memoized for n <= 16; larger n fall back to small_mul
+    pub fn small_generator_multiple(n: u8) -> Self {
+        match SMALL_GENERATOR_MULTIPLES.get(n as usize) {
+            Some(p) => p.clone(),
+            None => GENERATOR.small_mul(n),
+        }
+    }
+
+    //? Scalar multiplication by a u128, via the full-width `mul`
+    pub fn mul_u128(&self, scalar: u128) -> Self {
+        self.mul(&Fr::from_u128(scalar))
+    }
+
+    pub fn mul(&self, scalar: &Fr) -> Self {
+        self.mul_biguint(&scalar.n)
+    }
+
+    //? Binary double-and-add from the top bit down, over exactly the scalar's bit length
+    pub fn mul_msb(&self, scalar: &Fr) -> Self {
+        let mut res = Self::infinity();
+        for i in (0..scalar_bit_length(&scalar.n)).rev() {
+            res = res.double();
+            if scalar.n.bit(i as u64) {
+                res = res.add(self);
             }
-            base = base.double();
-            scalar >>= 1;
+        }
+        res
+    }
+
+    //? Swaps a and b when choice is 1, selecting coordinates with limb masks
+    fn ct_swap(a: &mut Self, b: &mut Self, choice: bool) {
+        let pick = |x: &Fp, y: &Fp, i: usize| Fp::ct_lookup(&[x.clone(), y.clone()], i);
+        let c = choice as usize;
+        let new_a = Self {
+            x: pick(&a.x, &b.x, c),
+            y: pick(&a.y, &b.y, c),
+            z: pick(&a.z, &b.z, c),
+        };
+        let new_b = Self {
+            x: pick(&a.x, &b.x, 1 - c),
+            y: pick(&a.y, &b.y, 1 - c),
+            z: pick(&a.z, &b.z, 1 - c),
+        };
+        *a = new_a;
+        *b = new_b;
+    }
+
+    //? Montgomery ladder over the 254 bits of Fp::to_ladder_bits: one add and one
+    //? double per bit whatever its value. Only the group-operation schedule is
+    //? data-independent; BigUint arithmetic and the infinity shortcuts inside
+    //? add/double (hit while the ladder is still on leading zeros) are not.
+    pub fn mul_ct(&self, scalar: &Fr) -> Self {
+        let mut r0 = Self::infinity();
+        let mut r1 = self.clone();
+        // r < p, so the scalar's Fp image has the same bits
+        for bit in Fp::new(scalar.n.clone()).to_ladder_bits() {
+            Self::ct_swap(&mut r0, &mut r1, bit);
+            r1 = r0.add(&r1);
+            r0 = r0.double();
+            Self::ct_swap(&mut r0, &mut r1, bit);
+        }
+        r0
+    }
+
+    //? Fixed 4-bit window scalar multiplication, MSB-first, for any unreduced scalar
+    pub fn mul_biguint(&self, scalar: &BigUint) -> Self {
+        self.mul_biguint_counted(scalar, |_, _| {})
+    }
+
+    //? mul_biguint's body, reporting each step to on_ops as (doubles, adds)
+    pub(crate) fn mul_biguint_counted<F: FnMut(usize, usize)>(
+        &self,
+        scalar: &BigUint,
+        mut on_ops: F,
+    ) -> Self {
+        let mut table = Vec::with_capacity(16);
+        table.push(Self::infinity());
+        for i in 1..16 {
+            let next = table[i - 1].add(self);
+            on_ops(0, 1);
+            table.push(next);
         }
 
+        let windows = scalar.bits().div_ceil(4);
+        let mut res = Self::infinity();
+        for w in (0..windows).rev() {
+            res = res.double_n(4);
+            on_ops(4, 0);
+            let digit = (0..4).fold(0usize, |acc, b| {
+                acc | ((scalar.bit(4 * w + b) as usize) << b)
+            });
+            if digit != 0 {
+                res = res.add(&table[digit]);
+                on_ops(0, 1);
+            }
+        }
         res
     }
+
+    //? Probabilistic batch validation: checks a single random combination
+    //? sum r_i * P_i instead of every point. The Jacobian formulas never use b,
+    //? so an off-curve P_i drags the sum off the curve except with negligible odds.
+    //? G1 has cofactor 1, so on-curve already implies membership in the group.
+    pub fn batch_check_random<R: rand::Rng>(points: &[G1], rng: &mut R) -> bool {
+        let scalars: Vec<Fr> = points.iter().map(|_| Fr::random(rng)).collect();
+        Self::msm(points, &scalars).is_on_curve()
+    }
+
+    //? [r]P == O through the ordinary scalar-mul path. Cofactor 1 makes this
+    //? redundant for on-curve points, but it catches bad params or off-curve input.
+    pub fn is_in_subgroup(&self) -> bool {
+        self.mul_biguint(Fr::modulus()).is_infinity()
+    }
+
+    //? sum_i c_i * B_i for a KZG commitment, with bases [s^i]G from the SRS
+    //? and coeffs the polynomial coefficients. A thin wrapper over msm.
+    pub fn generator_linear_combo(coeffs: &[Fr], bases: &[G1]) -> Self {
+        Self::msm(bases, coeffs)
+    }
+
+    //? Pippenger's bucket method for sum of scalar_i * P_i
+    pub fn msm(points: &[G1], scalars: &[Fr]) -> Self {
+        assert_eq!(
+            points.len(),
+            scalars.len(),
+            "points and scalars differ in length"
+        );
+        if points.is_empty() {
+            return Self::infinity();
+        }
+        let c = msm_window_bits(points.len());
+        let bits = max_scalar_bits();
+
+        let mut windows = Vec::with_capacity(bits.div_ceil(c));
+        for start in (0..bits).step_by(c) {
+            let mut buckets = vec![Self::infinity(); (1 << c) - 1];
+            for (p, s) in points.iter().zip(scalars) {
+                let digit = (0..c).fold(0usize, |acc, b| {
+                    acc | ((s.n.bit((start + b) as u64) as usize) << b)
+                });
+                if digit != 0 {
+                    buckets[digit - 1] = buckets[digit - 1].add(p);
+                }
+            }
+            // sum_j j * bucket_j via a running suffix sum
+            let mut running = Self::infinity();
+            let mut sum = Self::infinity();
+            for bucket in buckets.iter().rev() {
+                running = running.add(bucket);
+                sum = sum.add(&running);
+            }
+            windows.push(sum);
+        }
+
+        windows
+            .iter()
+            .rev()
+            .fold(Self::infinity(), |acc, w| acc.double_n(c).add(w))
+    }
+
+    //? Pippenger with signed window digits in (-2^(c-1), 2^(c-1)]: a digit d < 0
+    //? adds -P to bucket |d|, so each window needs only 2^(c-1) buckets
+    pub fn msm_signed(points: &[G1], scalars: &[Fr]) -> Self {
+        assert_eq!(
+            points.len(),
+            scalars.len(),
+            "points and scalars differ in length"
+        );
+        if points.is_empty() {
+            return Self::infinity();
+        }
+        let c = msm_window_bits(points.len());
+        let digits: Vec<Vec<i64>> = scalars.iter().map(|s| signed_digits(&s.n, c)).collect();
+        let negs: Vec<G1> = points.iter().map(|p| p.neg()).collect();
+
+        let mut windows = Vec::with_capacity(digits[0].len());
+        for w in 0..digits[0].len() {
+            let mut buckets = vec![Self::infinity(); 1 << (c - 1)];
+            for (i, d) in digits.iter().enumerate() {
+                let digit = d[w];
+                if digit > 0 {
+                    let j = digit as usize - 1;
+                    buckets[j] = buckets[j].add(&points[i]);
+                } else if digit < 0 {
+                    let j = (-digit) as usize - 1;
+                    buckets[j] = buckets[j].add(&negs[i]);
+                }
+            }
+            let mut running = Self::infinity();
+            let mut sum = Self::infinity();
+            for bucket in buckets.iter().rev() {
+                running = running.add(bucket);
+                sum = sum.add(&running);
+            }
+            windows.push(sum);
+        }
+
+        windows
+            .iter()
+            .rev()
+            .fold(Self::infinity(), |acc, w| acc.double_n(c).add(w))
+    }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::fp::Fp;
+//? Flags live in the top two bits of the first (big-endian) byte; p < 2^254 leaves them free
+const Y_IS_ODD: u8 = 1 << 7;
+const POINT_AT_INFINITY: u8 = 1 << 6;
 
-    #[test]
-    fn test_infinity() {
-        let inf = G1::infinity();
-        assert!(inf.is_infinity());
-        assert_eq!(inf.to_affine(), (Fp::zero(), Fp::zero()));
+fn decode_fp(bytes: &[u8; 32]) -> Result<Fp, Error> {
+    if !Fp::is_canonical_bytes(bytes) {
+        return Err(Error::NonCanonical);
     }
+    Ok(Fp::from_bytes_unchecked(bytes))
+}
 
-    #[test]
-    fn test_affine_conversion() {
-        let p = G1 {
-            x: Fp::new(3u32.into()),
-            y: Fp::new(6u32.into()),
-            z: Fp::one(),
-        };
-        let (x_aff, y_aff) = p.to_affine();
-        assert_eq!(x_aff, Fp::new(3u32.into()));
-        assert_eq!(y_aff, Fp::new(6u32.into()));
+//? Splits off the flag bits, rejecting both-set and nonzero infinity payloads
+fn split_flags(bytes: &[u8]) -> Result<(u8, Vec<u8>), Error> {
+    let flags = bytes[0] & (Y_IS_ODD | POINT_AT_INFINITY);
+    let mut rest = bytes.to_vec();
+    rest[0] &= !(Y_IS_ODD | POINT_AT_INFINITY);
+    if flags == Y_IS_ODD | POINT_AT_INFINITY {
+        return Err(Error::InvalidFlags);
+    }
+    if flags == POINT_AT_INFINITY && rest.iter().any(|b| *b != 0) {
+        return Err(Error::NonCanonical);
     }
+    Ok((flags, rest))
+}
 
-    #[test]
-    fn test_on_curve_known_point() {
-        let p = G1 {
-            x: Fp::new(1u32.into()),
-            y: Fp::new(2u32.into()),
-            z: Fp::one(),
-        };
-        assert!(p.is_on_curve());
+impl G1 {
+    //? Affine x, big-endian, with the parity of y in the top bit
+    pub fn to_bytes_compressed(&self) -> [u8; 32] {
+        if self.is_infinity() {
+            let mut out = [0u8; 32];
+            out[0] = POINT_AT_INFINITY;
+            return out;
+        }
+        let (x, y) = self.to_affine();
+        let mut out = x.to_bytes_be();
+        if y.n.bit(0) {
+            out[0] |= Y_IS_ODD;
+        }
+        out
     }
 
-    #[test]
-    fn test_double_vs_add() {
-        let p = G1 {
-            x: Fp::new(3u32.into()),
-            y: Fp::new(6u32.into()),
-            z: Fp::one(),
-        };
-        let double = p.double();
-        let add = p.add(&p);
-        assert_eq!(double.to_affine(), add.to_affine());
+    //? Affine x || y, big-endian; only the infinity flag is meaningful here
+    pub fn to_bytes_uncompressed(&self) -> [u8; 64] {
+        let mut out = [0u8; 64];
+        if self.is_infinity() {
+            out[0] = POINT_AT_INFINITY;
+            return out;
+        }
+        let (x, y) = self.to_affine();
+        out[..32].copy_from_slice(&x.to_bytes_be());
+        out[32..].copy_from_slice(&y.to_bytes_be());
+        out
     }
 
-    #[test]
-    fn test_addition_commutative() {
-        let p1 = G1 {
-            x: Fp::new(3u32.into()),
-            y: Fp::new(6u32.into()),
-            z: Fp::one(),
+    pub fn from_bytes_compressed(bytes: &[u8; 32]) -> Result<G1, Error> {
+        let (flags, rest) = split_flags(bytes)?;
+        if flags == POINT_AT_INFINITY {
+            return Ok(G1::infinity());
+        }
+        let x = decode_fp(rest.as_slice().try_into().unwrap())?;
+        let mut y = curve_rhs(&x).sqrt().ok_or(Error::NotOnCurve)?;
+        if y.n.bit(0) != (flags == Y_IS_ODD) {
+            y = -y;
+        }
+        Ok(G1 { x, y, z: Fp::one() })
+    }
+
+    //? Decode-then-multiply as one fallible step for verification code.
+    //? G1 has cofactor 1, so the on-curve check in decoding is full validation.
+    pub fn from_compressed_and_mul(bytes: &[u8; 32], scalar: &Fr) -> Result<G1, Error> {
+        Ok(G1::from_bytes_compressed(bytes)?.mul(scalar))
+    }
+
+    //? Compressed encoding as padded standard base64
+    pub fn to_base64(&self) -> String {
+        base64::encode(&self.to_bytes_compressed())
+    }
+
+    pub fn from_base64(s: &str) -> Result<G1, Error> {
+        let bytes: [u8; 32] = base64::decode(s)?
+            .try_into()
+            .map_err(|_| Error::InvalidLength)?;
+        G1::from_bytes_compressed(&bytes)
+    }
+
+    pub fn from_bytes_uncompressed(bytes: &[u8; 64]) -> Result<G1, Error> {
+        let (flags, rest) = split_flags(bytes)?;
+        match flags {
+            POINT_AT_INFINITY => return Ok(G1::infinity()),
+            Y_IS_ODD => return Err(Error::InvalidFlags),
+            _ => {}
+        }
+        let x = decode_fp(rest[..32].try_into().unwrap())?;
+        let y = decode_fp(rest[32..].try_into().unwrap())?;
+        if !affine_on_curve(&x, &y) {
+            return Err(Error::NotOnCurve);
+        }
+        Ok(G1 { x, y, z: Fp::one() })
+    }
+
+    //? Circuit witness [x, y, is_infinity] in affine form; infinity is [0, 0, 1]
+    pub fn to_witness(&self) -> Vec<Fp> {
+        if self.is_infinity() {
+            return vec![Fp::zero(), Fp::zero(), Fp::one()];
+        }
+        let (x, y) = self.to_affine();
+        vec![x, y, Fp::zero()]
+    }
+
+    //? Inverse of to_witness: the flag must be 0 or 1, and infinity must carry
+    //? zero coordinates
+    pub fn from_witness(witness: &[Fp]) -> Result<G1, Error> {
+        let [x, y, flag] = witness else {
+            return Err(Error::InvalidLength);
         };
-        let p2 = G1 {
-            x: Fp::new(5u32.into()),
-            y: Fp::new(1u32.into()),
+        if *flag == Fp::one() {
+            if !x.n.is_zero() || !y.n.is_zero() {
+                return Err(Error::NonCanonical);
+            }
+            return Ok(G1::infinity());
+        }
+        if !flag.n.is_zero() {
+            return Err(Error::InvalidFlags);
+        }
+        if !affine_on_curve(x, y) {
+            return Err(Error::NotOnCurve);
+        }
+        Ok(G1 {
+            x: x.clone(),
+            y: y.clone(),
             z: Fp::one(),
-        };
-        let sum1 = p1.add(&p2);
-        let sum2 = p2.add(&p1);
-        assert_eq!(sum1.to_affine(), sum2.to_affine());
+        })
     }
+}
 
-    #[test]
-    fn test_scalar_mul_u128() {
-        let p = G1 {
-            x: Fp::new(3u32.into()),
-            y: Fp::new(6u32.into()),
-            z: Fp::one(),
-        };
-        let res0 = p.mul_u128(0);
-        assert!(res0.is_infinity());
+//? By reference only: a by-value impl would shadow G1::mul in method-call syntax
+impl Mul<&Fr> for &G1 {
+    type Output = G1;
+    fn mul(self, rhs: &Fr) -> G1 {
+        G1::mul(self, rhs)
+    }
+}
 
-        let res1 = p.mul_u128(1);
-        assert_eq!(res1.to_affine(), p.to_affine());
+impl Mul<G1> for Fr {
+    type Output = G1;
+    fn mul(self, rhs: G1) -> G1 {
+        G1::mul(&rhs, &self)
+    }
+}
 
-        let res2 = p.mul_u128(2);
-        assert_eq!(res2.to_affine(), p.add(&p).to_affine());
+impl Mul<&G1> for &Fr {
+    type Output = G1;
+    fn mul(self, rhs: &G1) -> G1 {
+        G1::mul(rhs, self)
+    }
+}
 
-        let res3 = p.mul_u128(3);
-        assert_eq!(res3.to_affine(), p.add(&p).add(&p).to_affine());
+//? Prints the normalized affine point in hex rather than raw Jacobian limbs
+impl fmt::Debug for G1 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_infinity() {
+            return write!(f, "G1(infinity)");
+        }
+        let (x, y) = self.to_affine();
+        write!(f, "G1(x: {:#x}, y: {:#x})", x.n, y.n)
+    }
+}
+
+//? Pippenger window width for an MSM of n terms
+fn msm_window_bits(n: usize) -> usize {
+    if n < 32 {
+        3
+    } else {
+        (n as f64).ln().ceil() as usize + 2
+    }
+}
+
+//? Base-2^c digits of s in (-2^(c-1), 2^(c-1)], least significant first. A digit
+//? above half the base borrows 2^c from the next window, so one extra window
+//? holds the final carry.
+fn signed_digits(s: &BigUint, c: usize) -> Vec<i64> {
+    let windows = max_scalar_bits().div_ceil(c) + 1;
+    let half = 1i64 << (c - 1);
+    let mut carry = 0i64;
+    let mut digits = Vec::with_capacity(windows);
+    for w in 0..windows {
+        let raw = (0..c).fold(0i64, |acc, b| {
+            acc | ((s.bit((w * c + b) as u64) as i64) << b)
+        });
+        let mut d = raw + carry;
+        carry = 0;
+        if d > half {
+            d -= 1 << c;
+            carry = 1;
+        }
+        digits.push(d);
+    }
+    digits
+}
+
+//? Homogeneous projective coordinates: (X : Y : Z) is the affine point (X/Z, Y/Z).
+//? Uses the complete a = 0 formulas of Renes-Costello-Batina, so add and double
+//? need no special cases for infinity or equal inputs.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct G1Projective {
+    pub x: Fp,
+    pub y: Fp,
+    pub z: Fp,
+}
+
+impl G1Projective {
+    pub fn infinity() -> Self {
+        Self {
+            x: Fp::zero(),
+            y: Fp::one(),
+            z: Fp::zero(),
+        }
+    }
+
+    pub fn generator() -> Self {
+        Self::from(&G1::generator())
+    }
+
+    pub fn is_infinity(&self) -> bool {
+        self.z.n.is_zero()
+    }
+
+    pub fn to_affine(&self) -> (Fp, Fp) {
+        if self.is_infinity() {
+            return (Fp::zero(), Fp::zero());
+        }
+        let z_inv = self.z.inv();
+        (&self.x * &z_inv, &self.y * &z_inv)
+    }
+
+    pub fn neg(&self) -> Self {
+        Self {
+            x: self.x.clone(),
+            y: -self.y.clone(),
+            z: self.z.clone(),
+        }
+    }
+
+    //? Algorithm 9 of ePrint 2015/1060
+    pub fn double(&self) -> Self {
+        let t0 = &self.y * &self.y;
+        let z3 = t0.double().double().double();
+        let t1 = &self.y * &self.z;
+        let t2 = &(&self.z * &self.z) * &G1_B3;
+        let x3 = &t2 * &z3;
+        let y3 = &t0 + &t2;
+        let z3 = &t1 * &z3;
+        let t0 = &t0 - &t2.triple();
+        let y3 = &(&t0 * &y3) + &x3;
+        let t1 = &self.x * &self.y;
+        let x3 = (&t0 * &t1).double();
+        Self {
+            x: x3,
+            y: y3,
+            z: z3,
+        }
+    }
+
+    //? Algorithm 7 of ePrint 2015/1060
+    pub fn add(&self, other: &Self) -> Self {
+        let t0 = &self.x * &other.x;
+        let t1 = &self.y * &other.y;
+        let t2 = &self.z * &other.z;
+        let t3 = &(&(&self.x + &self.y) * &(&other.x + &other.y)) - &(&t0 + &t1);
+        let t4 = &(&(&self.y + &self.z) * &(&other.y + &other.z)) - &(&t1 + &t2);
+        let y3 = &(&(&self.x + &self.z) * &(&other.x + &other.z)) - &(&t0 + &t2);
+        let t0 = t0.triple();
+        let t2 = &t2 * &G1_B3;
+        let z3 = &t1 + &t2;
+        let t1 = &t1 - &t2;
+        let y3 = &y3 * &G1_B3;
+        let x3 = &(&t3 * &t1) - &(&t4 * &y3);
+        let y3 = &(&y3 * &t0) + &(&t1 * &z3);
+        let z3 = &(&z3 * &t4) + &(&t0 * &t3);
+        Self {
+            x: x3,
+            y: y3,
+            z: z3,
+        }
+    }
+}
+
+//? Jacobian (X, Y, Z) is projective (XZ, Y, Z^3); no inversion needed
+impl From<&G1> for G1Projective {
+    fn from(p: &G1) -> Self {
+        if p.is_infinity() {
+            return G1Projective::infinity();
+        }
+        G1Projective {
+            x: &p.x * &p.z,
+            y: p.y.clone(),
+            z: &(&p.z * &p.z) * &p.z,
+        }
+    }
+}
+
+//? Projective (X, Y, Z) is Jacobian (XZ, YZ^2, Z)
+impl From<&G1Projective> for G1 {
+    fn from(p: &G1Projective) -> Self {
+        if p.is_infinity() {
+            return G1::infinity();
+        }
+        G1 {
+            x: &p.x * &p.z,
+            y: &(&p.y * &p.z) * &p.z,
+            z: p.z.clone(),
+        }
+    }
+}
+
+//? Collects (point, scalar) terms one at a time; the Pippenger buckets are
+//? only built in finalize, once the window size for the full input is known
+#[derive(Clone, Debug, Default)]
+pub struct MsmAccumulator {
+    points: Vec<G1>,
+    scalars: Vec<Fr>,
+}
+
+impl MsmAccumulator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add(&mut self, point: &G1, scalar: &Fr) {
+        self.points.push(point.clone());
+        self.scalars.push(scalar.clone());
+    }
+
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    pub fn finalize(self) -> G1 {
+        G1::msm(&self.points, &self.scalars)
+    }
+}
+
+//? A G1 point known to be valid. The check runs once in `new`; since G1 has
+//? cofactor 1, on-curve is the whole of it, and holders never repeat it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CheckedG1(G1);
+
+impl CheckedG1 {
+    pub fn new(p: G1) -> Result<Self, Error> {
+        if !p.is_on_curve() {
+            return Err(Error::NotOnCurve);
+        }
+        Ok(CheckedG1(p))
+    }
+
+    pub fn point(&self) -> &G1 {
+        &self.0
+    }
+
+    pub fn into_inner(self) -> G1 {
+        self.0
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct G1Affine {
+    pub x: Fp,
+    pub y: Fp,
+    pub infinity: bool,
+}
+
+impl G1Affine {
+    //? Negates every point in place; affine negation is just y -> -y
+    pub fn batch_negate(points: &mut [G1Affine]) {
+        for p in points.iter_mut().filter(|p| !p.infinity) {
+            p.y = -p.y.clone();
+        }
+    }
+}
+
+impl From<&G1> for G1Affine {
+    fn from(p: &G1) -> Self {
+        let (x, y) = p.to_affine();
+        G1Affine {
+            x,
+            y,
+            infinity: p.is_infinity(),
+        }
+    }
+}
+
+impl From<&G1Affine> for G1 {
+    fn from(p: &G1Affine) -> Self {
+        if p.infinity {
+            return G1::infinity();
+        }
+        G1 {
+            x: p.x.clone(),
+            y: p.y.clone(),
+            z: Fp::one(),
+        }
+    }
+}
+
+//? Right-hand side x^3 + 3 of the curve equation, the one place b appears
+pub fn curve_rhs(x: &Fp) -> Fp {
+    &(&(x * x) * x) + &G1_B
+}
+
+//? Checks y^2 = x^3 + 3 for raw affine coordinates
+pub fn affine_on_curve(x: &Fp, y: &Fp) -> bool {
+    y * y == curve_rhs(x)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fp::Fp;
+    use num_bigint::BigInt;
+    use num_traits::One;
+    use rand::Rng;
+
+    // Textbook affine group law; None is the point at infinity
+    type AffineRef = Option<(Fp, Fp)>;
+
+    fn ref_add(a: &AffineRef, b: &AffineRef) -> AffineRef {
+        let (x1, y1) = match a {
+            None => return b.clone(),
+            Some(p) => p.clone(),
+        };
+        let (x2, y2) = match b {
+            None => return a.clone(),
+            Some(q) => q.clone(),
+        };
+        let lambda = if x1 == x2 {
+            if y1 != y2 || y1 == Fp::zero() {
+                return None;
+            }
+            let three = Fp::new(3u32.into());
+            let two = Fp::new(2u32.into());
+            three * x1.clone() * x1.clone() * (two * y1.clone()).inv()
+        } else {
+            (y2 - y1.clone()) * (x2.clone() - x1.clone()).inv()
+        };
+        let x3 = lambda.clone() * lambda.clone() - x1.clone() - x2;
+        let y3 = lambda * (x1 - x3.clone()) - y1;
+        Some((x3, y3))
+    }
+
+    fn ref_mul(p: &AffineRef, mut k: u64) -> AffineRef {
+        let mut res = None;
+        let mut base = p.clone();
+        while k > 0 {
+            if k & 1 == 1 {
+                res = ref_add(&res, &base);
+            }
+            base = ref_add(&base, &base);
+            k >>= 1;
+        }
+        res
+    }
+
+    // Lifts an affine point to Jacobian with a random non-trivial z
+    fn to_jacobian<R: Rng>(p: &AffineRef, rng: &mut R) -> G1 {
+        match p {
+            None => G1::infinity(),
+            Some((x, y)) => {
+                let z = Fp::new(rng.gen_range(2..u64::MAX).into());
+                let z2 = z.clone() * z.clone();
+                G1 {
+                    x: x.clone() * z2.clone(),
+                    y: y.clone() * z2 * z.clone(),
+                    z,
+                }
+            }
+        }
+    }
+
+    fn from_jacobian(p: &G1) -> AffineRef {
+        if p.is_infinity() {
+            None
+        } else {
+            Some(p.to_affine())
+        }
+    }
+
+    #[test]
+    fn test_jacobian_matches_affine_reference() {
+        let mut rng = rand::thread_rng();
+        let g: AffineRef = Some((Fp::new(1u32.into()), Fp::new(2u32.into())));
+        for _ in 0..20 {
+            let a = ref_mul(&g, rng.gen_range(1..u64::MAX));
+            let b = ref_mul(&g, rng.gen_range(1..u64::MAX));
+            let ja = to_jacobian(&a, &mut rng);
+            let jb = to_jacobian(&b, &mut rng);
+
+            assert!(ja.is_on_curve() && jb.is_on_curve());
+            assert_eq!(from_jacobian(&ja.add(&jb)), ref_add(&a, &b));
+            assert_eq!(from_jacobian(&ja.double()), ref_add(&a, &a));
+            assert_eq!(from_jacobian(&ja.add(&ja)), ref_add(&a, &a));
+            assert_eq!(from_jacobian(&ja.add(&ja.neg())), None);
+            assert_eq!(
+                from_jacobian(
+                    &G1::batch_add(std::slice::from_ref(&ja), &[G1::from(&G1Affine::from(&jb))])
+                        .unwrap()[0]
+                ),
+                ref_add(&a, &b)
+            );
+
+            let k: u64 = rng.gen_range(0..1 << 16);
+            assert_eq!(from_jacobian(&ja.mul_u128(k as u128)), ref_mul(&a, k));
+        }
+    }
+
+    #[test]
+    fn test_infinity() {
+        let inf = G1::infinity();
+        assert!(inf.is_infinity());
+        assert_eq!(inf.to_affine(), (Fp::zero(), Fp::zero()));
+    }
+
+    #[test]
+    fn test_affine_conversion() {
+        let p = G1 {
+            x: Fp::new(3u32.into()),
+            y: Fp::new(6u32.into()),
+            z: Fp::one(),
+        };
+        let (x_aff, y_aff) = p.to_affine();
+        assert_eq!(x_aff, Fp::new(3u32.into()));
+        assert_eq!(y_aff, Fp::new(6u32.into()));
+    }
+
+    #[test]
+    fn test_affine_with_zinv() {
+        let g = G1 {
+            x: Fp::new(1u32.into()),
+            y: Fp::new(2u32.into()),
+            z: Fp::one(),
+        };
+        let p = g.mul_u128(6);
+        let (x_aff, y_aff, z_inv) = p.to_affine_with_zinv();
+        assert_eq!(p.z.clone() * z_inv, Fp::one());
+        assert_eq!((x_aff, y_aff), p.to_affine());
+
+        let (_, _, inf_z_inv) = G1::infinity().to_affine_with_zinv();
+        assert_eq!(inf_z_inv, Fp::zero());
+    }
+
+    #[test]
+    fn test_on_curve_known_point() {
+        let p = G1 {
+            x: Fp::new(1u32.into()),
+            y: Fp::new(2u32.into()),
+            z: Fp::one(),
+        };
+        assert!(p.is_on_curve());
+    }
+
+    #[test]
+    fn test_is_generator() {
+        let g = G1::generator();
+        assert!(g.is_generator());
+        assert!(!g.double().is_generator());
+        assert!(!G1::infinity().is_generator());
+
+        // Same point with z = 2
+        let scaled = G1 {
+            x: Fp::new(4u32.into()),
+            y: Fp::new(16u32.into()),
+            z: Fp::new(2u32.into()),
+        };
+        assert!(scaled.is_generator());
+        assert!(g.mul_u128(5).eq_projective(&g.double().double().add(&g)));
+    }
+
+    #[test]
+    fn test_scalar_mul_operators() {
+        let p = G1::generator().mul_u128(9);
+        let k = -Fr::from_u128(0x1234_5678);
+        let expected = p.mul(&k);
+        assert!((&k * &p).eq_projective(&expected));
+        assert!((&p * &k).eq_projective(&expected));
+        assert!((k * p).eq_projective(&expected));
+    }
+
+    #[test]
+    fn test_small_generator_multiple() {
+        let g = G1::generator();
+        let five = g.add(&g).add(&g).add(&g).add(&g);
+        assert!(G1::small_generator_multiple(5).eq_projective(&five));
+        assert!(G1::small_generator_multiple(0).is_infinity());
+        for n in [1u8, 16, 17, 200] {
+            assert!(G1::small_generator_multiple(n).eq_projective(&g.mul_u128(n as u128)));
+        }
+    }
+
+    #[test]
+    fn test_equals_affine() {
+        let one = Fp::new(1u32.into());
+        let two = Fp::new(2u32.into());
+        let g = G1::generator();
+        assert!(g.equals_affine(&one, &two));
+        assert!(!g.equals_affine(&one, &-two.clone()));
+        assert!(!g.equals_affine(&two, &one));
+        assert!(!G1::infinity().equals_affine(&one, &two));
+
+        let scaled = G1 {
+            x: Fp::new(4u32.into()),
+            y: Fp::new(16u32.into()),
+            z: Fp::new(2u32.into()),
+        };
+        assert!(scaled.equals_affine(&one, &two));
+
+        let p = g.mul_u128(77);
+        let (x, y) = p.to_affine();
+        assert!(p.equals_affine(&x, &y));
+    }
+
+    #[test]
+    fn test_debug_prints_affine_hex() {
+        assert_eq!(format!("{:?}", G1::generator()), "G1(x: 0x1, y: 0x2)");
+        assert_eq!(format!("{:?}", G1::infinity()), "G1(infinity)");
+
+        let p = G1::generator().mul_u128(1000);
+        let (x, _) = p.to_affine();
+        assert!(format!("{:?}", p).contains(&format!("{:#x}", x.n)));
+    }
+
+    #[test]
+    fn test_infinity_results_are_canonical() {
+        // y = 0 makes the doubling's z3 = 2*Y1*Z1 vanish
+        let two_torsion_like = G1 {
+            x: Fp::new(5u32.into()),
+            y: Fp::zero(),
+            z: Fp::new(3u32.into()),
+        };
+        assert_eq!(two_torsion_like.double(), G1::infinity());
+        assert_eq!(two_torsion_like.add(&two_torsion_like), G1::infinity());
+
+        let non_canonical = G1 {
+            x: Fp::new(5u32.into()),
+            y: Fp::new(7u32.into()),
+            z: Fp::zero(),
+        };
+        assert_eq!(non_canonical.double(), G1::infinity());
+        assert_eq!(non_canonical.add(&non_canonical), G1::infinity());
+        assert_eq!(
+            G1::batch_add(&[G1::infinity()], &[non_canonical]).unwrap()[0],
+            G1::infinity()
+        );
+
+        let g = G1::generator();
+        assert_eq!(g.add(&g.mul_u128(0)), g);
+    }
+
+    #[test]
+    fn test_affine_on_curve() {
+        assert!(affine_on_curve(
+            &Fp::new(1u32.into()),
+            &Fp::new(2u32.into())
+        ));
+        assert!(affine_on_curve(
+            &Fp::new(1u32.into()),
+            &-Fp::new(2u32.into())
+        ));
+        assert!(!affine_on_curve(
+            &Fp::new(3u32.into()),
+            &Fp::new(6u32.into())
+        ));
+    }
+
+    #[test]
+    fn test_negate_slice_at() {
+        let g = G1::generator();
+        let original: Vec<G1> = (0..6).map(|k| g.mul_u128(k)).collect();
+        let indices = [0, 2, 5];
+
+        let mut table = original.clone();
+        G1::negate_slice_at(&mut table, &indices);
+        for (i, (p, q)) in table.iter().zip(&original).enumerate() {
+            if indices.contains(&i) {
+                assert!(p.eq_projective(&q.neg()));
+            } else {
+                assert_eq!(p, q);
+            }
+        }
+        assert_eq!(table[0], G1::infinity());
+
+        G1::negate_slice_at(&mut table, &indices);
+        assert_eq!(table, original);
+        G1::negate_slice_at(&mut table, &[1, 1]);
+        assert_eq!(table, original);
+    }
+
+    #[test]
+    fn test_affine_batch_negate() {
+        let g = G1::generator();
+        let original: Vec<G1Affine> = [g.clone(), G1::infinity(), g.mul_u128(9)]
+            .iter()
+            .map(G1Affine::from)
+            .collect();
+
+        let mut points = original.clone();
+        G1Affine::batch_negate(&mut points);
+        assert_eq!(points[0].y, -Fp::new(2u32.into()));
+        assert_eq!(points[1], original[1]);
+        assert!(
+            G1::from(&points[2])
+                .add(&G1::from(&original[2]))
+                .is_infinity()
+        );
+
+        G1Affine::batch_negate(&mut points);
+        assert_eq!(points, original);
+    }
+
+    #[test]
+    fn test_double_vs_add() {
+        let p = G1 {
+            x: Fp::new(3u32.into()),
+            y: Fp::new(6u32.into()),
+            z: Fp::one(),
+        };
+        let double = p.double();
+        let add = p.add(&p);
+        assert_eq!(double.to_affine(), add.to_affine());
+    }
+
+    #[test]
+    fn test_addition_commutative() {
+        let p1 = G1 {
+            x: Fp::new(3u32.into()),
+            y: Fp::new(6u32.into()),
+            z: Fp::one(),
+        };
+        let p2 = G1 {
+            x: Fp::new(5u32.into()),
+            y: Fp::new(1u32.into()),
+            z: Fp::one(),
+        };
+        let sum1 = p1.add(&p2);
+        let sum2 = p2.add(&p1);
+        assert_eq!(sum1.to_affine(), sum2.to_affine());
+    }
+
+    #[test]
+    fn test_scalar_mul_u128() {
+        let p = G1 {
+            x: Fp::new(3u32.into()),
+            y: Fp::new(6u32.into()),
+            z: Fp::one(),
+        };
+        let res0 = p.mul_u128(0);
+        assert!(res0.is_infinity());
+
+        let res1 = p.mul_u128(1);
+        assert_eq!(res1.to_affine(), p.to_affine());
+
+        let res2 = p.mul_u128(2);
+        assert_eq!(res2.to_affine(), p.add(&p).to_affine());
+
+        let res3 = p.mul_u128(3);
+        assert_eq!(res3.to_affine(), p.add(&p).add(&p).to_affine());
+    }
+
+    #[test]
+    fn test_mul_pow2_plus_one() {
+        let p = G1::generator().mul_u128(7);
+        assert_eq!(p.double_n(0), p);
+        for k in 0..10 {
+            let expected = p.mul_u128((1u128 << k) + 1);
+            assert_eq!(p.mul_pow2_plus_one(k).to_affine(), expected.to_affine());
+        }
+    }
+
+    #[test]
+    fn test_signed_linear_combo() {
+        let g = G1::generator();
+        let points = vec![g.clone(), g.mul_u128(3), g.mul_u128(10), G1::infinity()];
+        let coeffs = [5i64, -2, 0, -7];
+
+        let expected = g
+            .mul_u128(5)
+            .add(&g.mul_u128(3).neg().mul_u128(2))
+            .add(&g.mul_u128(10).mul_u128(0));
+        let combo = G1::signed_linear_combo(&points, &coeffs);
+        assert!(combo.eq_projective(&expected));
+
+        assert!(
+            G1::signed_linear_combo(std::slice::from_ref(&g), &[i64::MIN])
+                .eq_projective(&g.mul_u128(1u128 << 63).neg())
+        );
+        assert!(G1::signed_linear_combo(&[], &[]).is_infinity());
+    }
+
+    fn random_fr<R: Rng>(rng: &mut R) -> Fr {
+        let hi = Fr::from_u128(rng.gen_range(0..u128::MAX));
+        let lo = Fr::from_u128(rng.gen_range(0..u128::MAX));
+        hi * Fr::from_u128(1 << 127) * Fr::from_u128(2) + lo
+    }
+
+    #[test]
+    fn test_scalar_mul_full_width() {
+        let g = G1::generator();
+        let r = Fr::modulus();
+
+        assert!(g.mul(&Fr::zero()).is_infinity());
+        assert!(g.mul_biguint(r).is_infinity());
+        assert!(g.mul(&-Fr::one()).eq_projective(&g.neg()));
+        assert!(g.mul_biguint(&(r + 5u32)).eq_projective(&g.mul_u128(5)));
+        assert!(G1::infinity().mul(&Fr::from_u128(7)).is_infinity());
+
+        let mut acc = G1::infinity();
+        for k in 0..40u128 {
+            assert!(g.mul(&Fr::from_u128(k)).eq_projective(&acc));
+            acc = acc.add(&g);
+        }
+
+        let mut rng = rand::thread_rng();
+        let a: AffineRef = Some(g.to_affine());
+        for _ in 0..5 {
+            let k: u64 = rng.gen_range(0..u64::MAX);
+            assert_eq!(
+                from_jacobian(&g.mul(&Fr::from_u128(k as u128))),
+                ref_mul(&a, k)
+            );
+
+            let s = random_fr(&mut rng);
+            let t = random_fr(&mut rng);
+            let lhs = g.mul(&s).mul(&t);
+            assert!(lhs.eq_projective(&g.mul(&(s.clone() * t.clone()))));
+            assert!(g.mul(&s).add(&g.mul(&t)).eq_projective(&g.mul(&(s + t))));
+            assert!(lhs.is_on_curve());
+        }
+    }
+
+    #[test]
+    fn test_batch_add() {
+        let g = G1 {
+            x: Fp::new(1u32.into()),
+            y: Fp::new(2u32.into()),
+            z: Fp::one(),
+        };
+        let a: Vec<G1> = vec![g.clone(), g.mul_u128(3), G1::infinity(), g.mul_u128(5)];
+        let b: Vec<G1> = vec![g.clone(), g.clone(), g.mul_u128(7), g.mul_u128(11)];
+
+        let sums = G1::batch_add(&a, &b).unwrap();
+        assert_eq!(sums.len(), a.len());
+        for ((p, q), s) in a.iter().zip(&b).zip(&sums) {
+            assert_eq!(s.to_affine(), p.add(q).to_affine());
+        }
+
+        assert_eq!(
+            G1::batch_add(&a, &b[..2]),
+            Err(Error::LengthMismatch { left: 4, right: 2 })
+        );
+    }
+
+    #[test]
+    fn test_rerandomize() {
+        let mut rng = rand::thread_rng();
+        let c = G1::generator().mul_u128(42);
+        let h = G1::generator().mul_u128(0xabcdef);
+        let (blinded, r) = c.rerandomize(&h, &mut rng);
+        assert!(blinded.is_on_curve());
+        assert!(!blinded.eq_projective(&c));
+        assert!(blinded.add(&h.mul(&r).neg()).eq_projective(&c));
+    }
+
+    #[test]
+    fn test_bytes_round_trip() {
+        let mut rng = rand::thread_rng();
+        let mut points = vec![G1::generator(), G1::generator().neg(), G1::infinity()];
+        points.extend((0..3).map(|_| G1::generator().mul(&random_fr(&mut rng))));
+        for p in &points {
+            let c = G1::from_bytes_compressed(&p.to_bytes_compressed()).unwrap();
+            let u = G1::from_bytes_uncompressed(&p.to_bytes_uncompressed()).unwrap();
+            assert!(c.eq_projective(p));
+            assert!(u.eq_projective(p));
+        }
+
+        // Generator: x = 1, y = 2 is even
+        let mut g_bytes = [0u8; 32];
+        g_bytes[31] = 1;
+        assert_eq!(G1::generator().to_bytes_compressed(), g_bytes);
+        assert_eq!(G1::infinity().to_bytes_compressed()[0], POINT_AT_INFINITY);
+    }
+
+    #[test]
+    fn test_bytes_rejects() {
+        // x = 0 gives y^2 = 3, a non-residue
+        assert_eq!(
+            G1::from_bytes_compressed(&[0u8; 32]),
+            Err(Error::NotOnCurve)
+        );
+
+        let mut both = G1::generator().to_bytes_compressed();
+        both[0] |= Y_IS_ODD | POINT_AT_INFINITY;
+        assert_eq!(G1::from_bytes_compressed(&both), Err(Error::InvalidFlags));
+
+        let mut dirty_inf = G1::infinity().to_bytes_compressed();
+        dirty_inf[31] = 1;
+        assert_eq!(
+            G1::from_bytes_compressed(&dirty_inf),
+            Err(Error::NonCanonical)
+        );
+
+        // x = p is out of range
+        let mut p_bytes = [0u8; 32];
+        p_bytes.copy_from_slice(&Fp::modulus().to_bytes_be());
+        assert_eq!(
+            G1::from_bytes_compressed(&p_bytes),
+            Err(Error::NonCanonical)
+        );
+
+        let mut off_curve = G1::generator().to_bytes_uncompressed();
+        off_curve[63] = 3;
+        assert_eq!(
+            G1::from_bytes_uncompressed(&off_curve),
+            Err(Error::NotOnCurve)
+        );
+        let mut odd_flag = G1::generator().to_bytes_uncompressed();
+        odd_flag[0] |= Y_IS_ODD;
+        assert_eq!(
+            G1::from_bytes_uncompressed(&odd_flag),
+            Err(Error::InvalidFlags)
+        );
+    }
+
+    #[test]
+    fn test_msm_matches_naive() {
+        let mut rng = rand::thread_rng();
+        let g = G1::generator();
+        for n in [0usize, 1, 5, 50] {
+            let mut points: Vec<G1> = (0..n).map(|_| g.mul(&random_fr(&mut rng))).collect();
+            let mut scalars: Vec<Fr> = (0..n).map(|_| random_fr(&mut rng)).collect();
+            if n >= 5 {
+                scalars[1] = Fr::zero();
+                points[3] = G1::infinity();
+                scalars[4] = -Fr::one();
+            }
+            let naive = points
+                .iter()
+                .zip(&scalars)
+                .fold(G1::infinity(), |acc, (p, s)| acc.add(&p.mul(s)));
+            assert!(G1::msm(&points, &scalars).eq_projective(&naive));
+        }
+    }
+
+    #[test]
+    fn test_msm_signed() {
+        let mut rng = rand::thread_rng();
+        let g = G1::generator();
+        for n in [0usize, 1, 7, 40] {
+            let mut points: Vec<G1> = (0..n).map(|_| g.mul(&random_fr(&mut rng))).collect();
+            let mut scalars: Vec<Fr> = (0..n).map(|_| random_fr(&mut rng)).collect();
+            if n >= 7 {
+                scalars[1] = Fr::zero();
+                points[3] = G1::infinity();
+                scalars[4] = -Fr::one();
+                // Every window digit is 2^c - 1, carrying all the way up
+                scalars[5] = Fr::new((BigUint::one() << 253) - 1u32);
+            }
+            let naive = points
+                .iter()
+                .zip(&scalars)
+                .fold(G1::infinity(), |acc, (p, s)| acc.add(&p.mul(s)));
+            let signed = G1::msm_signed(&points, &scalars);
+            assert!(signed.eq_projective(&naive));
+            assert!(signed.eq_projective(&G1::msm(&points, &scalars)));
+        }
+    }
+
+    #[test]
+    fn test_signed_digits() {
+        let s = -Fr::from_u128(12345);
+        for c in [3usize, 4, 7] {
+            let digits = signed_digits(&s.n, c);
+            let half = 1i64 << (c - 1);
+            assert!(digits.iter().all(|d| -half < *d && *d <= half));
+            let value = digits
+                .iter()
+                .rev()
+                .fold(BigInt::zero(), |acc, d| (acc << c) + BigInt::from(*d));
+            assert_eq!(value, BigInt::from(s.n.clone()));
+        }
+    }
+
+    #[test]
+    fn test_generator_linear_combo() {
+        // Commit to 3 + 2X + 5X^2 with an SRS for s = 7: the result is [f(7)]G
+        let g = G1::generator();
+        let s = Fr::from_u128(7);
+        let mut bases = vec![g.clone()];
+        for i in 1..3 {
+            bases.push(bases[i - 1].mul(&s));
+        }
+        let coeffs = [Fr::from_u128(3), Fr::from_u128(2), Fr::from_u128(5)];
+        let naive = bases
+            .iter()
+            .zip(&coeffs)
+            .fold(G1::infinity(), |acc, (b, c)| acc.add(&b.mul(c)));
+        let commitment = G1::generator_linear_combo(&coeffs, &bases);
+        assert!(commitment.eq_projective(&naive));
+        assert!(commitment.eq_projective(&g.mul_u128(3 + 2 * 7 + 5 * 49)));
+    }
+
+    #[test]
+    fn test_mul_ct_matches_mul() {
+        let mut rng = rand::thread_rng();
+        let g = G1::generator();
+        for _ in 0..3 {
+            let s = random_fr(&mut rng);
+            assert!(g.mul_ct(&s).eq_projective(&g.mul(&s)));
+        }
+        assert!(g.mul_ct(&Fr::zero()).is_infinity());
+        assert!(g.mul_ct(&Fr::one()).eq_projective(&g));
+        assert!(g.mul_ct(&-Fr::one()).eq_projective(&g.neg()));
+    }
+
+    #[test]
+    fn test_batch_check_random() {
+        let mut rng = rand::thread_rng();
+        let g = G1::generator();
+        let mut points: Vec<G1> = (1..6).map(|k| g.mul_u128(k * 1000 + 7)).collect();
+        assert!(G1::batch_check_random(&points, &mut rng));
+        assert!(G1::batch_check_random(&[], &mut rng));
+
+        points[2] = G1 {
+            x: Fp::new(1u32.into()),
+            y: Fp::new(3u32.into()),
+            z: Fp::one(),
+        };
+        assert!(!points[2].is_on_curve());
+        assert!(!G1::batch_check_random(&points, &mut rng));
+    }
+
+    #[test]
+    fn test_batch_to_affine() {
+        let mut rng = rand::thread_rng();
+        let g = G1::generator();
+        let mut points: Vec<G1> = (0..6).map(|_| g.mul(&random_fr(&mut rng))).collect();
+        points.push(G1::infinity());
+        let affine = G1::batch_to_affine(&points);
+        for (p, a) in points.iter().zip(&affine) {
+            assert_eq!(*a, p.to_affine());
+        }
+    }
+
+    #[test]
+    fn test_checked_g1() {
+        let p = G1::generator().mul_u128(5);
+        let checked = CheckedG1::new(p.clone()).unwrap();
+        assert_eq!(checked.point(), &p);
+        assert_eq!(checked.into_inner(), p);
+        assert!(CheckedG1::new(G1::infinity()).is_ok());
+
+        let off_curve = G1 {
+            x: Fp::new(1u32.into()),
+            y: Fp::new(3u32.into()),
+            z: Fp::one(),
+        };
+        assert_eq!(CheckedG1::new(off_curve), Err(Error::NotOnCurve));
+    }
+
+    #[test]
+    fn test_curve_rhs() {
+        assert_eq!(curve_rhs(&Fp::one()), Fp::new(4u32.into()));
+        assert_eq!(curve_rhs(&Fp::zero()), Fp::new(3u32.into()));
+        let (x, y) = G1::generator().mul_u128(9).to_affine();
+        assert_eq!(&y * &y, curve_rhs(&x));
+    }
+
+    #[test]
+    fn test_alternating_sum() {
+        let g = G1::generator();
+        let (a, b, c, d) = (g.mul_u128(2), g.mul_u128(3), g.mul_u128(5), g.mul_u128(7));
+        let points = [a.clone(), b.clone(), c.clone(), d.clone()];
+        let sum = G1::alternating_sum(&points, &[false, true, false, true]).unwrap();
+        let manual = a.add(&b.neg()).add(&c).add(&d.neg());
+        assert!(sum.eq_projective(&manual));
+        assert!(sum.eq_projective(&g.mul(&-Fr::from_u128(3))));
+
+        assert!(G1::alternating_sum(&[], &[]).unwrap().is_infinity());
+        assert_eq!(
+            G1::alternating_sum(&points, &[true]),
+            Err(Error::LengthMismatch { left: 4, right: 1 })
+        );
+    }
+
+    #[test]
+    fn test_is_in_subgroup() {
+        let mut rng = rand::thread_rng();
+        assert!(G1::generator().is_in_subgroup());
+        assert!(G1::infinity().is_in_subgroup());
+        assert!(G1::generator().mul(&random_fr(&mut rng)).is_in_subgroup());
+
+        // (1, 3) lies on y^2 = x^3 + 8, whose group order is not r
+        let bad = G1 {
+            x: Fp::new(1u32.into()),
+            y: Fp::new(3u32.into()),
+            z: Fp::one(),
+        };
+        assert!(!bad.is_in_subgroup());
+    }
+
+    #[test]
+    fn test_witness_round_trip() {
+        for p in [
+            G1::generator(),
+            G1::generator().mul_u128(123),
+            G1::infinity(),
+        ] {
+            let w = p.to_witness();
+            assert_eq!(w.len(), 3);
+            assert!(G1::from_witness(&w).unwrap().eq_projective(&p));
+        }
+        assert_eq!(
+            G1::infinity().to_witness(),
+            vec![Fp::zero(), Fp::zero(), Fp::one()]
+        );
+
+        let one = Fp::one();
+        let two = Fp::new(2u32.into());
+        assert_eq!(
+            G1::from_witness(&[one.clone(), two.clone()]),
+            Err(Error::InvalidLength)
+        );
+        assert_eq!(
+            G1::from_witness(&[one.clone(), one.clone(), Fp::zero()]),
+            Err(Error::NotOnCurve)
+        );
+        assert_eq!(
+            G1::from_witness(&[one.clone(), two.clone(), two.clone()]),
+            Err(Error::InvalidFlags)
+        );
+        assert_eq!(
+            G1::from_witness(&[one, two, Fp::one()]),
+            Err(Error::NonCanonical)
+        );
+    }
+
+    #[test]
+    fn test_base64_round_trip() {
+        let g = G1::generator();
+        let encoded = g.to_base64();
+        assert_eq!(encoded, "AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAE=");
+        assert!(G1::from_base64(&encoded).unwrap().eq_projective(&g));
+        let p = g.mul_u128(1 << 90);
+        assert!(G1::from_base64(&p.to_base64()).unwrap().eq_projective(&p));
+
+        assert_eq!(G1::from_base64("not base64!"), Err(Error::InvalidBase64));
+        assert_eq!(G1::from_base64("AAAA"), Err(Error::InvalidLength));
+    }
+
+    #[test]
+    fn test_mul_msb_matches_mul() {
+        let mut rng = rand::thread_rng();
+        let g = G1::generator();
+        let mut scalars: Vec<Fr> = (0..3).map(|_| random_fr(&mut rng)).collect();
+        scalars.extend([
+            Fr::zero(),
+            Fr::one(),
+            Fr::from_u128(0b1011),
+            Fr::from_u128(rng.gen_range(0..u128::MAX) >> 100),
+            -Fr::one(),
+        ]);
+        for s in &scalars {
+            assert!(g.mul_msb(s).eq_projective(&g.mul(s)));
+        }
+    }
+
+    #[test]
+    fn test_projective_matches_jacobian() {
+        let mut rng = rand::thread_rng();
+        let g = G1::generator();
+        let a = g.mul(&random_fr(&mut rng));
+        let b = g.mul(&random_fr(&mut rng));
+        let (pa, pb) = (G1Projective::from(&a), G1Projective::from(&b));
+        assert_eq!(pa.to_affine(), a.to_affine());
+
+        // Same operation sequence in both representations
+        let mut jac = a.clone();
+        let mut proj = pa.clone();
+        for i in 0..10 {
+            if i % 3 == 0 {
+                jac = jac.double();
+                proj = proj.double();
+            } else {
+                jac = jac.add(&b);
+                proj = proj.add(&pb);
+            }
+            assert_eq!(proj.to_affine(), jac.to_affine());
+        }
+        assert!(G1::from(&proj).eq_projective(&jac));
+
+        // Complete formulas: doubling via add, infinity and inverses need no branches
+        assert_eq!(pa.add(&pa).to_affine(), a.double().to_affine());
+        assert!(pa.add(&pa.neg()).is_infinity());
+        assert_eq!(pa.add(&G1Projective::infinity()).to_affine(), a.to_affine());
+        assert!(G1Projective::infinity().double().is_infinity());
+        assert!(G1::from(&G1Projective::infinity()).is_infinity());
+        assert_eq!(G1Projective::generator().to_affine(), g.to_affine());
+    }
+
+    #[test]
+    fn test_compressed_parity_is_binding() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..3 {
+            let p = G1::generator().mul(&random_fr(&mut rng));
+            let bytes = p.to_bytes_compressed();
+            let decoded = G1::from_bytes_compressed(&bytes).unwrap();
+            assert_eq!(decoded.to_bytes_compressed(), bytes);
+
+            // A flipped parity bit is the encoding of -P, never a second encoding of P
+            let mut flipped = bytes;
+            flipped[0] ^= Y_IS_ODD;
+            let other = G1::from_bytes_compressed(&flipped).unwrap();
+            assert!(other.eq_projective(&p.neg()));
+            assert!(!other.eq_projective(&p));
+            assert_eq!(other.to_bytes_compressed(), flipped);
+        }
+    }
+
+    #[test]
+    fn test_small_mul() {
+        let p = G1::generator().mul_u128(777);
+        for n in 0..=20u8 {
+            assert!(
+                p.small_mul(n)
+                    .eq_projective(&p.mul(&Fr::from_u128(n as u128)))
+            );
+        }
+        assert!(p.small_mul(255).eq_projective(&p.mul_u128(255)));
+
+        // Known optimal lengths l(n)
+        for (n, len) in [(2u16, 1), (15, 5), (23, 6), (127, 10), (191, 11), (255, 10)] {
+            assert_eq!(SMALL_CHAINS[n as usize].len(), len, "n = {}", n);
+        }
+    }
+
+    #[test]
+    fn test_msm_accumulator() {
+        let mut rng = rand::thread_rng();
+        let g = G1::generator();
+        let points: Vec<G1> = (0..40).map(|_| g.mul(&random_fr(&mut rng))).collect();
+        let scalars: Vec<Fr> = (0..40).map(|_| random_fr(&mut rng)).collect();
+
+        let mut acc = MsmAccumulator::new();
+        assert!(acc.is_empty());
+        for (p, s) in points.iter().zip(&scalars) {
+            acc.add(p, s);
+        }
+        assert_eq!(acc.len(), 40);
+        assert!(acc.finalize().eq_projective(&G1::msm(&points, &scalars)));
+        assert!(MsmAccumulator::new().finalize().is_infinity());
+    }
+
+    #[test]
+    fn test_add_rescaled_negation() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..5 {
+            let (x, y) = G1::generator().mul(&random_fr(&mut rng)).to_affine();
+            let p = to_jacobian(&Some((x.clone(), y.clone())), &mut rng);
+            let neg_p = to_jacobian(&Some((x, -y)), &mut rng);
+            assert_ne!(p.z, neg_p.z);
+            assert!(p.add(&neg_p).is_infinity());
+            assert!(neg_p.add(&p).is_infinity());
+            // Same point under two other scalings still doubles
+            let p2 = to_jacobian(&Some(p.to_affine()), &mut rng);
+            assert!(p.add(&p2).eq_projective(&p.double()));
+        }
+    }
+
+    #[test]
+    fn test_from_compressed_and_mul() {
+        let k = Fr::from_u128(0xabcd_ef01_2345_6789);
+        let bytes = G1::generator().mul_u128(31).to_bytes_compressed();
+        let expected = G1::from_bytes_compressed(&bytes).unwrap().mul(&k);
+        assert!(
+            G1::from_compressed_and_mul(&bytes, &k)
+                .unwrap()
+                .eq_projective(&expected)
+        );
+
+        // x = p is out of range
+        let mut bad = [0u8; 32];
+        let p = Fp::modulus().to_bytes_be();
+        bad[32 - p.len()..].copy_from_slice(&p);
+        assert_eq!(
+            G1::from_compressed_and_mul(&bad, &k),
+            Err(Error::NonCanonical)
+        );
+        assert_eq!(
+            G1::from_compressed_and_mul(&[0xff; 32], &k),
+            Err(Error::InvalidFlags)
+        );
     }
 }
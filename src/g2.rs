@@ -0,0 +1,363 @@
+use crate::fp::Fp;
+use crate::fp2::Fp2;
+use crate::fr::Fr;
+use crate::params::G2_B;
+use lazy_static::lazy_static;
+use num_bigint::BigUint;
+
+fn fp(s: &str) -> Fp {
+    Fp::new(BigUint::parse_bytes(s.as_bytes(), 10).unwrap())
+}
+
+lazy_static! {
+    //? #E'(Fp2) = r * h with h = 2p - r
+    static ref COFACTOR: BigUint = Fp::modulus() * 2u32 - Fr::modulus();
+
+    static ref GENERATOR: G2 = G2 {
+        x: Fp2::new(
+            fp("10857046999023057135944570762232829481370756359578518086990519993285655852781"),
+            fp("11559732032986387107991004021392285783925812861821192530917403151452391805634"),
+        ),
+        y: Fp2::new(
+            fp("8495653923123431417604973247489272438418190587263600148770280649306958101930"),
+            fp("4082367875863433681332203403145435568316851327593401208105741076214120093531"),
+        ),
+        z: Fp2::one(),
+    };
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct G2 {
+    pub x: Fp2,
+    pub y: Fp2,
+    pub z: Fp2,
+}
+
+impl G2 {
+    pub fn infinity() -> Self {
+        Self {
+            x: Fp2::zero(),
+            y: Fp2::one(),
+            z: Fp2::zero(),
+        }
+    }
+
+    pub fn generator() -> Self {
+        GENERATOR.clone()
+    }
+
+    pub fn twist_b() -> Fp2 {
+        G2_B.clone()
+    }
+
+    pub fn is_infinity(&self) -> bool {
+        self.z.is_zero()
+    }
+
+    pub fn to_affine(&self) -> (Fp2, Fp2) {
+        if self.is_infinity() {
+            return (Fp2::zero(), Fp2::zero());
+        }
+        let z_inv = self.z.inv();
+        let z2 = z_inv.clone() * z_inv.clone();
+        let z3 = z2.clone() * z_inv;
+        let x_aff = self.x.clone() * z2;
+        let y_aff = self.y.clone() * z3;
+        (x_aff, y_aff)
+    }
+
+    //? Affine coordinates for many points with a single Fp2 inversion;
+    //? infinity maps to (0, 0) as in to_affine
+    pub fn batch_to_affine(points: &[G2]) -> Vec<(Fp2, Fp2)> {
+        let zs: Vec<Fp2> = points.iter().map(|p| p.z.clone()).collect();
+        points
+            .iter()
+            .zip(Fp2::batch_inverse(&zs))
+            .map(|(p, z_inv)| {
+                let z2 = z_inv.clone() * z_inv.clone();
+                let z3 = z2.clone() * z_inv;
+                (p.x.clone() * z2, p.y.clone() * z3)
+            })
+            .collect()
+    }
+
+    //? y^2 = x^3 + b' on the sextic twist
+    pub fn is_on_curve(&self) -> bool {
+        if self.is_infinity() {
+            return true;
+        }
+        let (x, y) = self.to_affine();
+        y.clone() * y == x.clone() * x.clone() * x + G2_B.clone()
+    }
+
+    pub fn neg(&self) -> Self {
+        Self {
+            x: self.x.clone(),
+            y: -self.y.clone(),
+            z: self.z.clone(),
+        }
+    }
+
+    //? Equality of the underlying points, independent of Jacobian scaling
+    pub fn eq_projective(&self, other: &Self) -> bool {
+        if self.is_infinity() || other.is_infinity() {
+            return self.is_infinity() && other.is_infinity();
+        }
+        let z1z1 = self.z.clone() * self.z.clone();
+        let z2z2 = other.z.clone() * other.z.clone();
+        self.x.clone() * z2z2.clone() == other.x.clone() * z1z1.clone()
+            && self.y.clone() * z2z2 * other.z.clone() == other.y.clone() * z1z1 * self.z.clone()
+    }
+
+    //? Doubling in Jacobian coordinates
+    pub fn double(&self) -> Self {
+        if self.is_infinity() {
+            return Self::infinity();
+        }
+
+        let xx = self.x.clone() * self.x.clone();
+        let yy = self.y.clone() * self.y.clone();
+        let yyyy = yy.clone() * yy.clone();
+        let t = (self.x.clone() + yy.clone()) * (self.x.clone() + yy) - xx.clone() - yyyy.clone();
+        let s = t.clone() + t; // 2*S
+        let m = xx.clone() + xx.clone() + xx; // 3*XX
+        let yyyy2 = yyyy.clone() + yyyy;
+        let yyyy4 = yyyy2.clone() + yyyy2;
+        let x3 = m.clone() * m.clone() - s.clone() - s.clone();
+        let y3 = m * (s - x3.clone()) - yyyy4.clone() - yyyy4; // 8*YYYY
+        let yz = self.y.clone() * self.z.clone();
+        let z3 = yz.clone() + yz; // 2*Y1*Z1
+        if z3.is_zero() {
+            return Self::infinity();
+        }
+        Self {
+            x: x3,
+            y: y3,
+            z: z3,
+        }
+    }
+
+    //? Addition in Jacobian coordinates
+    pub fn add(&self, other: &Self) -> Self {
+        if self.is_infinity() {
+            return other.clone();
+        }
+        if other.is_infinity() {
+            return self.clone();
+        }
+
+        let z1z1 = self.z.clone() * self.z.clone();
+        let z2z2 = other.z.clone() * other.z.clone();
+        let u1 = self.x.clone() * z2z2.clone();
+        let u2 = other.x.clone() * z1z1.clone();
+        let s1 = self.y.clone() * z2z2.clone() * other.z.clone();
+        let s2 = other.y.clone() * z1z1.clone() * self.z.clone();
+
+        if u1 == u2 {
+            if s1 == s2 {
+                return self.double();
+            } else {
+                return Self::infinity();
+            }
+        }
+
+        let h = u2 - u1.clone();
+        let i = (h.clone() + h.clone()) * (h.clone() + h.clone());
+        let j = h.clone() * i.clone();
+        let r = (s2.clone() - s1.clone()) + (s2 - s1.clone());
+        let v = u1 * i;
+
+        let x3 = r.clone() * r.clone() - j.clone() - v.clone() - v.clone();
+        let y3 = r * (v - x3.clone()) - s1.clone() * j.clone() - s1 * j;
+        let z3 =
+            ((self.z.clone() + other.z.clone()) * (self.z.clone() + other.z.clone()) - z1z1 - z2z2)
+                * h;
+
+        Self {
+            x: x3,
+            y: y3,
+            z: z3,
+        }
+    }
+
+    pub fn mul(&self, scalar: &Fr) -> Self {
+        self.mul_biguint(&scalar.n)
+    }
+
+    //? Fixed 4-bit window scalar multiplication, MSB-first, for any unreduced scalar
+    pub fn mul_biguint(&self, scalar: &BigUint) -> Self {
+        let mut table = Vec::with_capacity(16);
+        table.push(Self::infinity());
+        for i in 1..16 {
+            let next = table[i - 1].add(self);
+            table.push(next);
+        }
+
+        let windows = scalar.bits().div_ceil(4);
+        let mut res = Self::infinity();
+        for w in (0..windows).rev() {
+            for _ in 0..4 {
+                res = res.double();
+            }
+            let digit = (0..4).fold(0usize, |acc, b| {
+                acc | ((scalar.bit(4 * w + b) as usize) << b)
+            });
+            if digit != 0 {
+                res = res.add(&table[digit]);
+            }
+        }
+        res
+    }
+
+    //? Unlike G1, the twist has a large cofactor, so this check is not free
+    pub fn is_in_subgroup(&self) -> bool {
+        self.mul_biguint(Fr::modulus()).is_infinity()
+    }
+
+    //? Maps any twist point into the order-r subgroup
+    pub fn clear_cofactor(&self) -> Self {
+        self.mul_biguint(&COFACTOR)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct G2Affine {
+    pub x: Fp2,
+    pub y: Fp2,
+    pub infinity: bool,
+}
+
+impl From<&G2> for G2Affine {
+    fn from(p: &G2) -> Self {
+        let (x, y) = p.to_affine();
+        G2Affine {
+            x,
+            y,
+            infinity: p.is_infinity(),
+        }
+    }
+}
+
+impl From<&G2Affine> for G2 {
+    fn from(p: &G2Affine) -> Self {
+        if p.infinity {
+            return G2::infinity();
+        }
+        G2 {
+            x: p.x.clone(),
+            y: p.y.clone(),
+            z: Fp2::one(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // On the twist but outside the order-r subgroup (x = 2 + u)
+    fn non_subgroup_point() -> G2 {
+        G2 {
+            x: Fp2::new(Fp::new(2u32.into()), Fp::one()),
+            y: Fp2::new(
+                fp("7292567877523311580221095596750716176434782432868683424513645834767876293070"),
+                fp("19659275751359636165940301690575149581329631496732780143538578556285923319774"),
+            ),
+            z: Fp2::one(),
+        }
+    }
+
+    #[test]
+    fn test_infinity() {
+        let inf = G2::infinity();
+        assert!(inf.is_infinity());
+        assert!(inf.is_on_curve());
+        assert_eq!(inf.to_affine(), (Fp2::zero(), Fp2::zero()));
+    }
+
+    #[test]
+    fn test_twist_b() {
+        let nine_plus_u = Fp2::new(Fp::new(9u32.into()), Fp::one());
+        assert_eq!(
+            G2::twist_b() * nine_plus_u,
+            Fp2::new(Fp::new(3u32.into()), Fp::zero())
+        );
+    }
+
+    #[test]
+    fn test_generator_on_curve() {
+        let g = G2::generator();
+        assert!(g.is_on_curve());
+        assert!(g.double().is_on_curve());
+        assert!(g.double().add(&g).is_on_curve());
+    }
+
+    #[test]
+    fn test_double_vs_add() {
+        let g = G2::generator();
+        assert_eq!(g.double().to_affine(), g.add(&g).to_affine());
+        let p = g.double().add(&g);
+        assert_eq!(p.double().to_affine(), p.add(&p).to_affine());
+    }
+
+    #[test]
+    fn test_addition_commutative() {
+        let p1 = G2::generator();
+        let p2 = p1.double().double();
+        assert_eq!(p1.add(&p2).to_affine(), p2.add(&p1).to_affine());
+        assert!(p1.add(&p1.neg()).is_infinity());
+    }
+
+    #[test]
+    fn test_scalar_mul() {
+        let g = G2::generator();
+        assert!(g.mul(&Fr::zero()).is_infinity());
+        assert!(g.mul(&Fr::from_u128(3)).eq_projective(&g.double().add(&g)));
+        assert!(g.mul(&-Fr::one()).eq_projective(&g.neg()));
+
+        let a = Fr::from_u128(123456789);
+        let b = Fr::from_u128(987654321);
+        assert!(
+            g.mul(&a)
+                .mul(&b)
+                .eq_projective(&g.mul(&(a.clone() * b.clone())))
+        );
+        assert!(g.mul(&a).add(&g.mul(&b)).eq_projective(&g.mul(&(a + b))));
+    }
+
+    #[test]
+    fn test_subgroup_and_cofactor() {
+        assert!(G2::generator().is_in_subgroup());
+
+        let p = non_subgroup_point();
+        assert!(p.is_on_curve());
+        assert!(!p.is_in_subgroup());
+
+        let cleared = p.clear_cofactor();
+        assert!(cleared.is_on_curve());
+        assert!(!cleared.is_infinity());
+        assert!(cleared.is_in_subgroup());
+    }
+
+    #[test]
+    fn test_affine_round_trip_and_batch() {
+        let g = G2::generator();
+        let mut points: Vec<G2> = [2u128, 5, 1 << 70]
+            .iter()
+            .map(|k| g.mul(&Fr::from_u128(*k)))
+            .collect();
+        points.push(G2::infinity());
+
+        for p in &points {
+            let a = G2Affine::from(p);
+            assert_eq!(a.infinity, p.is_infinity());
+            assert!(G2::from(&a).eq_projective(p));
+        }
+        assert!(G2::from(&G2Affine::from(&G2::infinity())).is_infinity());
+
+        let affine = G2::batch_to_affine(&points);
+        for (p, a) in points.iter().zip(&affine) {
+            assert_eq!(*a, p.to_affine());
+        }
+    }
+}
@@ -1,2 +1,16 @@
-mod fp;
-mod g1;
\ No newline at end of file
+mod base64;
+#[cfg(feature = "compat")]
+pub mod compat;
+pub mod error;
+pub mod fp;
+pub mod fp12;
+pub mod fp2;
+pub mod fp6;
+pub mod fr;
+pub mod g1;
+pub mod g2;
+pub mod pairing;
+pub mod params;
+#[cfg(feature = "perf")]
+pub mod perf;
+pub mod reduction;
@@ -0,0 +1,394 @@
+//? Optimal ate pairing e: G1 x G2 -> Fp12 on BN254.
+//? G2 lives on the D-type twist y^2 = x^3 + 3/xi and maps into E(Fp12) via
+//? (x, y) -> (x*w^2, y*w^3), so every line evaluated at P is sparse.
+
+use crate::fp::Fp;
+use crate::fp2::Fp2;
+use crate::fp12::{Fp12, twist_frobenius_coeffs};
+use crate::fr::Fr;
+use crate::g1::G1;
+use crate::g2::G2;
+use crate::params::{ATE_LOOP_COUNT_NAF, G2_B3};
+use lazy_static::lazy_static;
+use num_bigint::BigUint;
+use num_traits::{One, Zero};
+
+lazy_static! {
+    //? (p^4 - p^2 + 1) / r, the exponent of the hard part
+    static ref HARD_EXPONENT: BigUint = {
+        let p2 = Fp::modulus() * Fp::modulus();
+        let num = &p2 * &p2 - &p2 + BigUint::one();
+        debug_assert!((&num % Fr::modulus()).is_zero());
+        num / Fr::modulus()
+    };
+}
+
+//? (c0, c3, c4) weights of a sparse line value
+type Line = (Fp2, Fp2, Fp2);
+
+//? Homogeneous projective point (X : Y : Z) on the twist, x = X/Z and y = Y/Z
+type Projective = (Fp2, Fp2, Fp2);
+
+//? f * line for a line in (c0, c3, c4) form
+fn mul_by_line(f: &Fp12, line: &Line) -> Fp12 {
+    f.mul_by_034(&line.0, &line.1, &line.2)
+}
+
+//? Line with slope n/d on the twist, evaluated at P and scaled by d, where c4
+//? is d times its constant term: d*yP - n*xP*w + c4*w^3
+fn line_eval(n: &Fp2, d: &Fp2, c4: Fp2, p: &(Fp, Fp)) -> Line {
+    (d.mul_by_fp(&p.1), -n.mul_by_fp(&p.0), c4)
+}
+
+//? Returns 2T and the tangent at T evaluated at P, without inversions.
+//? With slope n/d = 3X^2 / 2YZ, the curve equation Y^2 Z = X^3 + b'Z^3 turns
+//? the constant term into Y^2 - 3b'Z^2 after scaling the line by 2YZ.
+fn double_step(t: &Projective, p: &(Fp, Fp)) -> (Projective, Line) {
+    let (x, y, z) = t;
+    let xx = x.clone() * x.clone();
+    let n = xx.clone() + xx.clone() + xx;
+    let d = (y.clone() + y.clone()) * z.clone();
+    let zz = z.clone() * z.clone();
+    let line = line_eval(&n, &d, y.clone() * y.clone() - G2_B3.clone() * zz, p);
+
+    let dd = d.clone() * d.clone();
+    let ddd = dd.clone() * d.clone();
+    let a = n.clone() * n.clone() * z.clone() - (x.clone() + x.clone()) * dd.clone();
+    let x3 = d * a.clone();
+    let y3 = n * (x.clone() * dd - a) - y.clone() * ddd.clone();
+    let z3 = ddd * z.clone();
+    ((x3, y3, z3), line)
+}
+
+//? Returns T + Q for affine Q and the chord through them evaluated at P,
+//? without inversions; the slope is n/d = (y2*Z - Y) / (x2*Z - X)
+fn add_step(t: &Projective, q: &(Fp2, Fp2), p: &(Fp, Fp)) -> (Projective, Line) {
+    let (x1, y1, z1) = t;
+    let (x2, y2) = q;
+    let n = y2.clone() * z1.clone() - y1.clone();
+    let d = x2.clone() * z1.clone() - x1.clone();
+    let line = line_eval(&n, &d, n.clone() * x2.clone() - d.clone() * y2.clone(), p);
+
+    let dd = d.clone() * d.clone();
+    let ddd = dd.clone() * d.clone();
+    let x2z1 = x2.clone() * z1.clone();
+    let a = n.clone() * n.clone() * z1.clone() - dd.clone() * (x1.clone() + x2z1.clone());
+    let x3 = d * a.clone();
+    let y3 = n * (dd * x2z1 - a) - y2.clone() * ddd.clone() * z1.clone();
+    let z3 = ddd * z1.clone();
+    ((x3, y3, z3), line)
+}
+
+//? The p-power Frobenius endomorphism carried over to the twist
+fn twist_frobenius(q: &(Fp2, Fp2)) -> (Fp2, Fp2) {
+    let (g2, g3) = twist_frobenius_coeffs();
+    (q.0.conjugate() * g2, q.1.conjugate() * g3)
+}
+
+//? Product over j of f_{6x+2,Q_j}(P) and the Frobenius correction lines, with
+//? one shared accumulator so each step squares once for all Q_j. T_j is kept
+//? projective so no step inverts. Vertical lines and the Fp2 factors scaling
+//? each line lie in Fp6 and vanish under the final exponentiation.
+fn multi_miller_loop(p: &(Fp, Fp), qs: &[(Fp2, Fp2)]) -> Fp12 {
+    let q_negs: Vec<(Fp2, Fp2)> = qs.iter().map(|q| (q.0.clone(), -q.1.clone())).collect();
+
+    let mut f = Fp12::one();
+    let mut ts: Vec<Projective> = qs
+        .iter()
+        .map(|q| (q.0.clone(), q.1.clone(), Fp2::one()))
+        .collect();
+    for digit in ATE_LOOP_COUNT_NAF.iter().rev().skip(1) {
+        f = f.clone() * f;
+        for t in ts.iter_mut() {
+            let (t2, line) = double_step(t, p);
+            f = mul_by_line(&f, &line);
+            *t = t2;
+        }
+        let addends = match digit {
+            1 => qs,
+            -1 => &q_negs[..],
+            _ => continue,
+        };
+        for (t, addend) in ts.iter_mut().zip(addends) {
+            let (t2, line) = add_step(t, addend, p);
+            f = mul_by_line(&f, &line);
+            *t = t2;
+        }
+    }
+
+    // T = [6x+2]Q; add pi(Q), then -pi^2(Q)
+    for (t, q) in ts.iter().zip(qs) {
+        let q1 = twist_frobenius(q);
+        let q2 = twist_frobenius(&q1);
+        let q2_neg = (q2.0, -q2.1);
+        let (t2, line) = add_step(t, &q1, p);
+        f = mul_by_line(&f, &line);
+        let (_, line) = add_step(&t2, &q2_neg, p);
+        f = mul_by_line(&f, &line);
+    }
+    f
+}
+
+//? Either input at infinity gives one: the identity pairs to the identity,
+//? and it has no affine coordinates for the loop to run on
+pub fn miller_loop(p: &G1, q: &G2) -> Fp12 {
+    if p.is_infinity() || q.is_infinity() {
+        return Fp12::one();
+    }
+    multi_miller_loop(&p.to_affine(), &[q.to_affine()])
+}
+
+//? f^((p^6 - 1)(p^2 + 1))
+pub fn final_exponentiation_easy(f: &Fp12) -> Fp12 {
+    let f1 = f.conjugate() * f.inv();
+    f1.frobenius_map(2) * f1
+}
+
+//? f^((p^4 - p^2 + 1) / r)
+pub fn final_exponentiation_hard(f: &Fp12) -> Fp12 {
+    f.pow(&HARD_EXPONENT)
+}
+
+//? Raises a Miller loop output to (p^12 - 1) / r
+pub fn final_exponentiation(f: &Fp12) -> Fp12 {
+    final_exponentiation_hard(&final_exponentiation_easy(f))
+}
+
+//? e(O, Q) = e(P, O) = 1, returned without a Miller loop or final exponentiation
+pub fn pairing(p: &G1, q: &G2) -> Fp12 {
+    if p.is_infinity() || q.is_infinity() {
+        return Fp12::one();
+    }
+    final_exponentiation(&miller_loop(p, q))
+}
+
+//? e(P, Q) == target, rejecting on the c0.c0.c0 coefficient before comparing
+//? the other eleven
+pub fn pairing_equals(p: &G1, q: &G2, target: &Fp12) -> bool {
+    let e = pairing(p, q);
+    e.c0.c0.c0 == target.c0.c0.c0 && e == *target
+}
+
+//? prod_j e(P, Q_j): P is normalized once, the Miller loops share one
+//? accumulator, and a single final exponentiation finishes the product
+pub fn pairing_fixed_g1(p: &G1, qs: &[G2]) -> Fp12 {
+    if p.is_infinity() {
+        return Fp12::one();
+    }
+    let qs: Vec<(Fp2, Fp2)> = qs
+        .iter()
+        .filter(|q| !q.is_infinity())
+        .map(|q| q.to_affine())
+        .collect();
+    final_exponentiation(&multi_miller_loop(&p.to_affine(), &qs))
+}
+
+//? Checks prod e(P_i, Q_i) == 1 where some pairs are fixed by a verification
+//? key. Their Miller loop product is computed once and reused for every check.
+pub struct PairingVerifier {
+    fixed: Fp12,
+}
+
+impl PairingVerifier {
+    pub fn new(fixed_pairs: &[(G1, G2)]) -> Self {
+        let fixed = fixed_pairs
+            .iter()
+            .fold(Fp12::one(), |acc, (p, q)| acc * miller_loop(p, q));
+        PairingVerifier { fixed }
+    }
+
+    //? Miller loop product of the fixed pairs, before final exponentiation
+    pub fn fixed_miller_product(&self) -> &Fp12 {
+        &self.fixed
+    }
+
+    pub fn verify(&self, pairs: &[(G1, G2)]) -> bool {
+        let f = pairs
+            .iter()
+            .fold(self.fixed.clone(), |acc, (p, q)| acc * miller_loop(p, q));
+        final_exponentiation(&f).is_one()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::params::G2_B;
+
+    // Affine Miller loop with exact (unscaled) lines, as a reference for the
+    // projective one: each step pays one Fp2 inversion for the slope
+    fn affine_line(lambda: &Fp2, t: &(Fp2, Fp2), p: &(Fp, Fp)) -> Line {
+        (
+            Fp2::new(p.1.clone(), Fp::zero()),
+            -lambda.mul_by_fp(&p.0),
+            lambda.clone() * t.0.clone() - t.1.clone(),
+        )
+    }
+
+    fn affine_add(t: &(Fp2, Fp2), q: &(Fp2, Fp2), p: &(Fp, Fp)) -> ((Fp2, Fp2), Line) {
+        let lambda = if t == q {
+            let xx = t.0.clone() * t.0.clone();
+            (xx.clone() + xx.clone() + xx) * (t.1.clone() + t.1.clone()).inv()
+        } else {
+            (q.1.clone() - t.1.clone()) * (q.0.clone() - t.0.clone()).inv()
+        };
+        let x3 = lambda.clone() * lambda.clone() - t.0.clone() - q.0.clone();
+        let y3 = lambda.clone() * (t.0.clone() - x3.clone()) - t.1.clone();
+        let line = affine_line(&lambda, t, p);
+        ((x3, y3), line)
+    }
+
+    fn affine_miller_loop(p: &(Fp, Fp), q: &(Fp2, Fp2)) -> Fp12 {
+        let q_neg = (q.0.clone(), -q.1.clone());
+        let mut f = Fp12::one();
+        let mut t = q.clone();
+        for digit in ATE_LOOP_COUNT_NAF.iter().rev().skip(1) {
+            f = f.clone() * f;
+            let (t2, line) = affine_add(&t, &t, p);
+            f = mul_by_line(&f, &line);
+            t = t2;
+            let addend = match digit {
+                1 => q,
+                -1 => &q_neg,
+                _ => continue,
+            };
+            let (t2, line) = affine_add(&t, addend, p);
+            f = mul_by_line(&f, &line);
+            t = t2;
+        }
+        let q1 = twist_frobenius(q);
+        let q2 = twist_frobenius(&q1);
+        let (t2, line) = affine_add(&t, &q1, p);
+        f = mul_by_line(&f, &line);
+        let (_, line) = affine_add(&t2, &(q2.0, -q2.1), p);
+        mul_by_line(&f, &line)
+    }
+
+    #[test]
+    fn test_projective_matches_affine() {
+        for (a, b) in [(1u128, 1u128), (0xdead_beef, 12345)] {
+            let p = G1::generator().mul(&Fr::from_u128(a));
+            let q = G2::generator().mul(&Fr::from_u128(b));
+            let reference = affine_miller_loop(&p.to_affine(), &q.to_affine());
+            let f = miller_loop(&p, &q);
+            // The Miller values differ by the Fp2 line scalings; the pairings agree
+            assert_eq!(final_exponentiation(&f), final_exponentiation(&reference));
+            assert_eq!(pairing(&p, &q), final_exponentiation(&reference));
+        }
+    }
+
+    #[test]
+    fn test_double_step_stays_on_twist() {
+        let q = G2::generator().to_affine();
+        let p = G1::generator().to_affine();
+        let mut t = (q.0.clone(), q.1.clone(), Fp2::one());
+        for _ in 0..5 {
+            t = double_step(&t, &p).0;
+            t = add_step(&t, &q, &p).0;
+            // Y^2 Z = X^3 + b' Z^3
+            let (x, y, z) = t.clone();
+            let zz = z.clone() * z.clone();
+            assert_eq!(
+                y.clone() * y * z.clone(),
+                x.clone() * x.clone() * x + G2_B.clone() * zz * z
+            );
+        }
+    }
+
+    #[test]
+    fn test_non_degenerate() {
+        let e = pairing(&G1::generator(), &G2::generator());
+        assert!(!e.is_one());
+        // The output lies in the order-r subgroup of Fp12*
+        assert!(e.pow(Fr::modulus()).is_one());
+    }
+
+    #[test]
+    fn test_bilinearity() {
+        let p = G1::generator();
+        let q = G2::generator();
+        let a = Fr::from_u128(0xdead_beef_1234_5678);
+        let b = -Fr::from_u128(0x9876_5432_abcd);
+        let e = pairing(&p, &q);
+
+        let lhs = pairing(&p.mul(&a), &q.mul(&b));
+        assert_eq!(lhs, e.pow(&(a.clone() * b.clone()).n));
+        assert_eq!(pairing(&p.mul(&a), &q), pairing(&p, &q.mul(&a)));
+    }
+
+    #[test]
+    fn test_linear_in_g1() {
+        let q = G2::generator().mul(&Fr::from_u128(7));
+        let p1 = G1::generator().mul(&Fr::from_u128(11));
+        let p2 = G1::generator().mul(&Fr::from_u128(13));
+        assert_eq!(
+            pairing(&p1.add(&p2), &q),
+            pairing(&p1, &q) * pairing(&p2, &q)
+        );
+        assert_eq!(pairing(&p1.neg(), &q), pairing(&p1, &q).inv());
+    }
+
+    #[test]
+    fn test_infinity() {
+        let p = G1::generator().mul(&Fr::from_u128(3));
+        let q = G2::generator().mul(&Fr::from_u128(5));
+        assert!(pairing(&G1::infinity(), &q).is_one());
+        assert!(pairing(&p, &G2::infinity()).is_one());
+        assert!(pairing(&G1::infinity(), &G2::infinity()).is_one());
+        assert!(miller_loop(&G1::infinity(), &q).is_one());
+        assert!(miller_loop(&p, &G2::infinity()).is_one());
+    }
+
+    #[test]
+    fn test_pairing_equals() {
+        let p = G1::generator().mul(&Fr::from_u128(3));
+        let q = G2::generator().mul(&Fr::from_u128(4));
+        let target = pairing(&G1::generator(), &G2::generator().mul(&Fr::from_u128(12)));
+        assert!(pairing_equals(&p, &q, &target));
+        assert!(!pairing_equals(&p, &q, &target.unitary_inverse()));
+        assert!(!pairing_equals(&p, &q, &Fp12::one()));
+
+        // Same c0.c0.c0, different elsewhere: the full comparison must catch it
+        let mut close = target.clone();
+        close.c1.c2.c1 = close.c1.c2.c1.clone() + Fp::one();
+        assert!(!pairing_equals(&p, &q, &close));
+    }
+
+    #[test]
+    fn test_pairing_fixed_g1() {
+        let p = G1::generator().mul(&Fr::from_u128(5));
+        let qs: Vec<G2> = [3u128, 17, 101]
+            .iter()
+            .map(|k| G2::generator().mul(&Fr::from_u128(*k)))
+            .collect();
+        let expected = qs.iter().fold(Fp12::one(), |acc, q| acc * pairing(&p, q));
+        assert_eq!(pairing_fixed_g1(&p, &qs), expected);
+
+        let mut with_inf = qs[..1].to_vec();
+        with_inf.push(G2::infinity());
+        assert_eq!(pairing_fixed_g1(&p, &with_inf), pairing(&p, &qs[0]));
+        assert!(pairing_fixed_g1(&p, &[]).is_one());
+    }
+
+    #[test]
+    fn test_pairing_verifier() {
+        let g = G1::generator();
+        let h = G2::generator();
+        let a = Fr::from_u128(1234);
+        let b = Fr::from_u128(5678);
+        let verifier = PairingVerifier::new(&[(g.mul(&a), h.mul(&b))]);
+
+        // e(aG, bH) * e(-abG, H) == 1
+        let good = [(g.mul(&-(a.clone() * b.clone())), h.clone())];
+        let bad = [(g.mul(&-a.clone()), h.clone())];
+        assert!(verifier.verify(&good));
+        assert!(!verifier.verify(&bad));
+
+        let direct = pairing(&g.mul(&a), &h.mul(&b)) * pairing(&good[0].0, &good[0].1);
+        assert!(direct.is_one());
+        assert_eq!(
+            final_exponentiation(verifier.fixed_miller_product()),
+            pairing(&g.mul(&a), &h.mul(&b))
+        );
+    }
+}
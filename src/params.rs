@@ -0,0 +1,122 @@
+use crate::fp::Fp;
+use crate::fp2::Fp2;
+use crate::fr::Fr;
+use crate::g1::G1;
+use lazy_static::lazy_static;
+use num_bigint::BigUint;
+use num_traits::{One, Zero};
+
+lazy_static! {
+    //? G1: y^2 = x^3 + b
+    pub static ref G1_B: Fp = Fp::new(3u32.into());
+
+    //? G2 twist: y^2 = x^3 + b' with b' = b / (9 + u)
+    pub static ref G2_B: Fp2 = Fp2::new(G1_B.clone(), Fp::zero())
+        * Fp2::new(Fp::new(9u32.into()), Fp::one()).inv();
+
+    //? 3b and 3b', as used by the projective formulas
+    pub static ref G1_B3: Fp = G1_B.triple();
+    pub static ref G2_B3: Fp2 = G2_B.clone() + G2_B.clone() + G2_B.clone();
+}
+
+//? BN254 curve parameter x; p and r are degree-4 polynomials in x
+pub const BN_X: u64 = 4965661367192848881;
+
+//? Optimal ate Miller loop length 6x + 2
+pub const ATE_LOOP_COUNT: u128 = 6 * BN_X as u128 + 2;
+
+//? Non-adjacent form of 6x + 2, least significant digit first
+pub const ATE_LOOP_COUNT_NAF: [i8; 66] = [
+    0, 0, 0, 1, 0, 1, 0, -1, 0, 0, -1, 0, 0, 0, 1, 0, 0, -1, 0, -1, 0, 0, 0, 1, 0, -1, 0, 0, 0, 0,
+    -1, 0, 0, 1, 0, -1, 0, 0, 1, 0, 0, 0, 0, 0, -1, 0, 0, -1, 0, 1, 0, -1, 0, 0, 0, -1, 0, -1, 0,
+    0, 0, 1, 0, -1, 0, 1,
+];
+
+//? p(x) = 36x^4 + 36x^3 + 24x^2 + 6x + 1
+fn p_from_x() -> BigUint {
+    let x = BigUint::from(BN_X);
+    let x2 = &x * &x;
+    36u32 * &x2 * &x2 + 36u32 * &x2 * &x + 24u32 * &x2 + 6u32 * &x + 1u32
+}
+
+//? Trace of Frobenius t(x) = 6x^2 + 1, so r = p + 1 - t
+fn trace_from_x() -> BigUint {
+    let x = BigUint::from(BN_X);
+    6u32 * &x * &x + 1u32
+}
+
+//? Cross-checks the hardcoded p, r and generator against each other and against x:
+//? both moduli follow the BN polynomials, #E(Fp) = p + 1 - t equals r (cofactor 1),
+//? r divides p^12 - 1 (embedding degree 12), and [r]G = O.
+pub fn validate() -> bool {
+    let p = Fp::modulus();
+    let r = Fr::modulus();
+    *p == p_from_x()
+        && p + BigUint::one() - trace_from_x() == *r
+        && (p.modpow(&BigUint::from(12u32), r) + r - BigUint::one()) % r == BigUint::zero()
+        && G1::generator().is_on_curve()
+        && G1::generator().mul_biguint(r).is_infinity()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ate_loop_count() {
+        assert_eq!(ATE_LOOP_COUNT, 29793968203157093288);
+    }
+
+    #[test]
+    fn test_ate_loop_count_naf() {
+        let value = ATE_LOOP_COUNT_NAF
+            .iter()
+            .rev()
+            .fold(0i128, |acc, d| 2 * acc + *d as i128);
+        assert_eq!(value, ATE_LOOP_COUNT as i128);
+
+        for pair in ATE_LOOP_COUNT_NAF.windows(2) {
+            assert!(pair[0] == 0 || pair[1] == 0);
+        }
+        assert_eq!(*ATE_LOOP_COUNT_NAF.last().unwrap(), 1);
+    }
+
+    #[test]
+    fn test_validate() {
+        assert!(validate());
+        assert_eq!(*Fp::modulus(), p_from_x());
+        assert_eq!(
+            Fp::modulus() + BigUint::one() - trace_from_x(),
+            *Fr::modulus()
+        );
+        // r does not divide p^k - 1 for any smaller k dividing 12
+        for k in [1u32, 2, 3, 4, 6] {
+            let pk = Fp::modulus().modpow(&BigUint::from(k), Fr::modulus());
+            assert_ne!(pk, BigUint::one());
+        }
+        assert!(
+            !G1::generator()
+                .mul_biguint(&(Fr::modulus() - 1u32))
+                .is_infinity()
+        );
+    }
+
+    #[test]
+    fn test_curve_coefficients() {
+        assert_eq!(*G1_B, Fp::new(3u32.into()));
+        assert_eq!(*G1_B3, Fp::new(9u32.into()));
+        assert_eq!(
+            G2_B3.clone(),
+            G2_B.clone() * Fp2::new(Fp::new(3u32.into()), Fp::zero())
+        );
+        assert_eq!(
+            G2_B.clone() * Fp2::new(Fp::new(9u32.into()), Fp::one()),
+            Fp2::new(G1_B.clone(), Fp::zero())
+        );
+
+        let (x, y) = G1::generator().to_affine();
+        assert_eq!(&y * &y, &(&(&x * &x) * &x) + &G1_B);
+        let (x, y) = crate::g2::G2::generator().to_affine();
+        assert_eq!(y.clone() * y, x.clone() * x.clone() * x + G2_B.clone());
+    }
+}
@@ -0,0 +1,55 @@
+//? Performance guardrails, compiled only with the `perf` feature so timing
+//? assertions stay out of normal test runs.
+
+use crate::fr::Fr;
+use crate::g1::G1;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct OpCount {
+    pub doubles: usize,
+    pub adds: usize,
+}
+
+impl G1 {
+    //? mul, counting the group operations it performs
+    pub fn mul_with_opcount(&self, scalar: &Fr) -> (Self, OpCount) {
+        let mut ops = OpCount::default();
+        let res = self.mul_biguint_counted(&scalar.n, |doubles, adds| {
+            ops.doubles += doubles;
+            ops.adds += adds;
+        });
+        (res, ops)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{Duration, Instant};
+
+    // Generous even for unoptimized builds; meant to catch order-of-magnitude regressions
+    const MUL_THRESHOLD: Duration = Duration::from_millis(500);
+
+    #[test]
+    fn test_mul_regression() {
+        let g = G1::generator();
+        let s = -Fr::from_u128(0x1234_5678_9abc_def0);
+
+        let (res, ops) = g.mul_with_opcount(&s);
+        assert!(res.eq_projective(&g.mul(&s)));
+        // 15 table adds, 4 doubles per 4-bit window, at most one add per window
+        let windows = s.n.bits().div_ceil(4) as usize;
+        assert_eq!(ops.doubles, 4 * windows);
+        assert!(ops.adds <= 15 + windows);
+
+        let start = Instant::now();
+        let _ = g.mul(&s);
+        let elapsed = start.elapsed();
+        assert!(
+            elapsed < MUL_THRESHOLD,
+            "mul took {:?}, threshold {:?}",
+            elapsed,
+            MUL_THRESHOLD
+        );
+    }
+}
@@ -0,0 +1,170 @@
+//? Interchangeable reductions of a product mod p, for benchmarking only.
+//? Fp's operators always reduce with NaiveModulo; nothing here changes that.
+
+use crate::fp::Fp;
+use lazy_static::lazy_static;
+use num_bigint::BigUint;
+use num_traits::One;
+
+const MONT_BITS: u64 = 256;
+
+lazy_static! {
+    //? Barrett constant floor(4^k / p) with k = bits(p)
+    static ref BARRETT_MU: BigUint = (BigUint::one() << (2 * Fp::modulus().bits())) / Fp::modulus();
+
+    static ref MONT_R_MASK: BigUint = (BigUint::one() << MONT_BITS) - BigUint::one();
+
+    //? -p^-1 mod 2^256, by Newton iteration (each step doubles the correct bits)
+    static ref MONT_P_PRIME: BigUint = {
+        let r = BigUint::one() << MONT_BITS;
+        let p = Fp::modulus();
+        let mut inv = BigUint::one();
+        for _ in 0..9 {
+            let pinv = (p * &inv) & &*MONT_R_MASK;
+            inv = (&inv * (BigUint::from(2u32) + &r - pinv)) & &*MONT_R_MASK;
+        }
+        &r - inv
+    };
+
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ReductionStrategy {
+    //? BigUint's division-based remainder, as used by Fp's operators
+    #[default]
+    NaiveModulo,
+    Barrett,
+    //? One REDC; operands and result stay in Montgomery form
+    Montgomery,
+}
+
+impl ReductionStrategy {
+    pub const ALL: [ReductionStrategy; 3] = [
+        ReductionStrategy::NaiveModulo,
+        ReductionStrategy::Barrett,
+        ReductionStrategy::Montgomery,
+    ];
+
+    //? Reduces a product t < p^2 of two operands. NaiveModulo and Barrett return
+    //? t mod p; Montgomery expects Montgomery-form operands and returns
+    //? t * 2^-256 mod p, the Montgomery form of their product.
+    pub fn reduce(self, t: &BigUint) -> BigUint {
+        match self {
+            ReductionStrategy::NaiveModulo => t % Fp::modulus(),
+            ReductionStrategy::Barrett => barrett(t),
+            ReductionStrategy::Montgomery => redc(t),
+        }
+    }
+}
+
+fn barrett(t: &BigUint) -> BigUint {
+    let p = Fp::modulus();
+    let k = p.bits();
+    let q = ((t >> (k - 1)) * &*BARRETT_MU) >> (k + 1);
+    let mut r = t - q * p;
+    while r >= *p {
+        r -= p;
+    }
+    r
+}
+
+//? t * 2^-256 mod p for t < 2^256 * p
+fn redc(t: &BigUint) -> BigUint {
+    let p = Fp::modulus();
+    let m = ((t & &*MONT_R_MASK) * &*MONT_P_PRIME) & &*MONT_R_MASK;
+    let mut u = (t + m * p) >> MONT_BITS;
+    if u >= *p {
+        u -= p;
+    }
+    u
+}
+
+impl Fp {
+    //? self * rhs reduced with the given strategy, for benchmarks. With
+    //? Montgomery, both operands and the result hold Montgomery-form values
+    //? (see to_montgomery), so conversions stay outside the timed loop.
+    pub fn mul_with(&self, rhs: &Fp, strategy: ReductionStrategy) -> Fp {
+        Fp {
+            n: strategy.reduce(&(&self.n * &rhs.n)),
+        }
+    }
+
+    //? a * 2^256 mod p as little-endian limbs: the Montgomery form used by
+    //? ReductionStrategy::Montgomery. Fp itself stores the plain value.
+    pub fn to_montgomery(&self) -> [u64; 4] {
+        Fp::new(&self.n << MONT_BITS).to_limbs()
+    }
+
+    //? Inverse of to_montgomery; limbs at or above p are accepted and reduced
+    pub fn from_montgomery(limbs: [u64; 4]) -> Fp {
+        Fp {
+            n: redc(&Fp::from_limbs(limbs).n),
+        }
+    }
+}
+
+//? Product of two Montgomery-form values, staying in Montgomery form
+pub fn montgomery_mul(a: [u64; 4], b: [u64; 4]) -> [u64; 4] {
+    Fp::from_limbs(a)
+        .mul_with(&Fp::from_limbs(b), ReductionStrategy::Montgomery)
+        .to_limbs()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::Rng;
+
+    #[test]
+    fn test_strategies_agree() {
+        let mut rng = rand::thread_rng();
+        let max = -Fp::one();
+        let mut inputs = vec![(Fp::zero(), max.clone()), (max.clone(), max)];
+        for _ in 0..20 {
+            let mut a = [0u8; 32];
+            let mut b = [0u8; 32];
+            rng.fill(&mut a);
+            rng.fill(&mut b);
+            inputs.push((
+                Fp::new(BigUint::from_bytes_be(&a)),
+                Fp::new(BigUint::from_bytes_be(&b)),
+            ));
+        }
+        for (a, b) in &inputs {
+            let expected = a * b;
+            for strategy in ReductionStrategy::ALL {
+                let product = if strategy == ReductionStrategy::Montgomery {
+                    let am = Fp::from_limbs(a.to_montgomery());
+                    let bm = Fp::from_limbs(b.to_montgomery());
+                    Fp::from_montgomery(am.mul_with(&bm, strategy).to_limbs())
+                } else {
+                    a.mul_with(b, strategy)
+                };
+                assert_eq!(product, expected, "{:?}", strategy);
+            }
+        }
+        assert_eq!(ReductionStrategy::default(), ReductionStrategy::NaiveModulo);
+    }
+
+    #[test]
+    fn test_montgomery_form() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..10 {
+            let mut bytes = [0u8; 32];
+            rng.fill(&mut bytes);
+            let a = Fp::new(BigUint::from_bytes_be(&bytes));
+            let b = -a.clone() * Fp::new(7u32.into());
+            assert_eq!(Fp::from_montgomery(a.to_montgomery()), a);
+
+            let product = montgomery_mul(a.to_montgomery(), b.to_montgomery());
+            assert_eq!(Fp::from_montgomery(product), &a * &b);
+            assert_eq!(product, (&a * &b).to_montgomery());
+        }
+        // R mod p is the Montgomery form of one
+        assert_eq!(
+            Fp::from_limbs(Fp::one().to_montgomery()).n,
+            (BigUint::one() << MONT_BITS) % Fp::modulus()
+        );
+        assert_eq!(Fp::zero().to_montgomery(), [0u64; 4]);
+    }
+}